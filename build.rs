@@ -0,0 +1,44 @@
+//! Exposes the host APIs compiled into the linked PortAudio library as `portaudio_<name>` cfgs,
+//! so that host-specific extension modules are only compiled when they are present, and the
+//! PortAudio versions whose API its header includes as `portaudio_v<major>_<minor>` cfgs.
+
+use std::env;
+
+/// The host APIs that `portaudio-sys2` may report.
+const HOST_APIS: &[&str] = &["alsa", "jack", "oss", "pulseaudio"];
+
+/// The header versions whose API is only used when `portaudio-sys2` reports them: 19.5 added
+/// `Pa_GetVersionInfo`.
+const HEADER_VERSIONS: &[(u32, u32)] = &[(19, 5)];
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=DEP_PORTAUDIO_HOST_APIS");
+    println!("cargo:rerun-if-env-changed=DEP_PORTAUDIO_HEADER_VERSION");
+
+    let cfgs: Vec<String> = HOST_APIS
+        .iter()
+        .map(|api| format!("portaudio_{}", api))
+        .collect();
+    println!("cargo:rustc-check-cfg=cfg({})", cfgs.join(", "));
+
+    let host_apis = env::var("DEP_PORTAUDIO_HOST_APIS").unwrap_or_default();
+    for api in host_apis.split(',').filter(|api| HOST_APIS.contains(api)) {
+        println!("cargo:rustc-cfg=portaudio_{}", api);
+    }
+
+    let cfgs: Vec<String> = HEADER_VERSIONS
+        .iter()
+        .map(|&(major, minor)| format!("portaudio_v{}_{}", major, minor))
+        .collect();
+    println!("cargo:rustc-check-cfg=cfg({})", cfgs.join(", "));
+
+    let header_version = env::var("DEP_PORTAUDIO_HEADER_VERSION").unwrap_or_default();
+    let mut parts = header_version.split('.').map(|part| part.parse::<u32>());
+    if let (Some(Ok(major)), Some(Ok(minor))) = (parts.next(), parts.next()) {
+        for &(cfg_major, cfg_minor) in HEADER_VERSIONS {
+            if (cfg_major, cfg_minor) <= (major, minor) {
+                println!("cargo:rustc-cfg=portaudio_v{}_{}", cfg_major, cfg_minor);
+            }
+        }
+    }
+}
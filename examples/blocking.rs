@@ -51,7 +51,7 @@ fn run() -> Result<(), pa::Error> {
         pa::StreamParameters::<f32>::new(def_output, CHANNELS, INTERLEAVED, latency);
 
     // Check that the stream format is supported.
-    pa.is_duplex_format_supported(input_params, output_params, SAMPLE_RATE)?;
+    pa.is_duplex_format_supported(input_params.clone(), output_params.clone(), SAMPLE_RATE)?;
 
     // Construct the settings with which we'll open our duplex stream.
     let settings = pa::DuplexStreamSettings::new(input_params, output_params, SAMPLE_RATE, FRAMES);
@@ -94,7 +94,7 @@ fn run() -> Result<(), pa::Error> {
         // If there are frames available, let's take them and add them to our buffer.
         if in_frames > 0 {
             let input_samples = stream.read(in_frames)?;
-            buffer.extend(input_samples.into_iter());
+            buffer.extend(input_samples);
             println!("Read {:?} frames from the input stream.", in_frames);
         }
 
@@ -116,8 +116,8 @@ fn run() -> Result<(), pa::Error> {
             let n_write_samples = write_frames as usize * CHANNELS as usize;
 
             stream.write(write_frames, |output| {
-                for i in 0..n_write_samples {
-                    output[i] = buffer.pop_front().unwrap();
+                for sample in output.iter_mut().take(n_write_samples) {
+                    *sample = buffer.pop_front().unwrap();
                 }
                 println!("Wrote {:?} frames to the output stream.", out_frames);
             })?;
@@ -46,7 +46,7 @@ fn run() -> Result<(), pa::Error> {
         );
         for &sample_rate in &STANDARD_SAMPLE_RATES {
             if pa
-                .is_input_format_supported(input_params, sample_rate)
+                .is_input_format_supported(input_params.clone(), sample_rate)
                 .is_ok()
             {
                 println!("\t{}hz", sample_rate);
@@ -59,7 +59,7 @@ fn run() -> Result<(), pa::Error> {
         );
         for &sample_rate in &STANDARD_SAMPLE_RATES {
             if pa
-                .is_output_format_supported(output_params, sample_rate)
+                .is_output_format_supported(output_params.clone(), sample_rate)
                 .is_ok()
             {
                 println!("\t{}hz", sample_rate);
@@ -70,7 +70,11 @@ fn run() -> Result<(), pa::Error> {
                  in_channels, out_channels);
         for &sample_rate in &STANDARD_SAMPLE_RATES {
             if pa
-                .is_duplex_format_supported(input_params, output_params, sample_rate)
+                .is_duplex_format_supported(
+                    input_params.clone(),
+                    output_params.clone(),
+                    sample_rate,
+                )
                 .is_ok()
             {
                 println!("\t{}hz", sample_rate);
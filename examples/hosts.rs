@@ -1,5 +1,5 @@
 //! Prints all Host APIs that are available on the system and that this instance of PortAudio can
-//! support.
+//! support, along with the devices belonging to each.
 
 extern crate portaudio as pa;
 
@@ -17,8 +17,15 @@ fn run() -> Result<(), pa::Error> {
 
     println!("Default Host API: {:?}", pa.default_host_api());
     println!("All Host APIs:");
-    for host in pa.host_apis() {
-        println!("{:#?}", host);
+    for host in pa.host_apis()? {
+        let host = host?;
+        println!("{:#?}", host.info());
+        println!("Default input: {:?}", host.default_input());
+        println!("Default output: {:?}", host.default_output());
+        for device in host.devices() {
+            let (idx, info) = device?;
+            println!("    {:?}: {}", idx, info.name);
+        }
     }
 
     Ok(())
@@ -49,7 +49,7 @@ fn run() -> Result<(), pa::Error> {
     let output_params = pa::StreamParameters::new(def_output, CHANNELS, INTERLEAVED, latency);
 
     // Check that the stream format is supported.
-    pa.is_duplex_format_supported(input_params, output_params, SAMPLE_RATE)?;
+    pa.is_duplex_format_supported(input_params.clone(), output_params.clone(), SAMPLE_RATE)?;
 
     // Construct the settings with which we'll open our duplex stream.
     let settings = pa::DuplexStreamSettings::new(input_params, output_params, SAMPLE_RATE, FRAMES);
@@ -70,7 +70,7 @@ fn run() -> Result<(), pa::Error> {
                              frames,
                              time,
                              ..
-                         }| {
+                         }: pa::DuplexStreamCallbackArgs<f32, f32>| {
         let current_time = time.current;
         let prev_time = maybe_last_time.unwrap_or(current_time);
         let dt = current_time - prev_time;
@@ -98,7 +98,7 @@ fn run() -> Result<(), pa::Error> {
     stream.start()?;
 
     // Loop while the non-blocking stream is active.
-    while let true = stream.is_active()? {
+    while stream.is_active()? {
         // Do some stuff!
         while let Ok(count_down) = receiver.try_recv() {
             println!("count_down: {:?}", count_down);
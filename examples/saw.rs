@@ -40,7 +40,8 @@ fn run() -> Result<(), pa::Error> {
     // This routine will be called by the PortAudio engine when audio is needed. It may called at
     // interrupt level on some machines so don't do anything that could mess up the system like
     // dynamic resource allocation or IO.
-    let callback = move |pa::OutputStreamCallbackArgs { buffer, frames, .. }| {
+    let callback = move |args: pa::OutputStreamCallbackArgs<f32>| {
+        let pa::OutputStreamCallbackArgs { buffer, frames, .. } = args;
         let mut idx = 0;
         for _ in 0..frames {
             buffer[idx] = left_saw;
@@ -31,8 +31,8 @@ fn run() -> Result<(), pa::Error> {
 
     // Initialise sinusoidal wavetable.
     let mut sine = [0.0; TABLE_SIZE];
-    for i in 0..TABLE_SIZE {
-        sine[i] = (i as f64 / TABLE_SIZE as f64 * PI * 2.0).sin() as f32;
+    for (i, sample) in sine.iter_mut().enumerate() {
+        *sample = (i as f64 / TABLE_SIZE as f64 * PI * 2.0).sin() as f32;
     }
     let mut left_phase = 0;
     let mut right_phase = 0;
@@ -47,7 +47,8 @@ fn run() -> Result<(), pa::Error> {
     // This routine will be called by the PortAudio engine when audio is needed. It may called at
     // interrupt level on some machines so don't do anything that could mess up the system like
     // dynamic resource allocation or IO.
-    let callback = move |pa::OutputStreamCallbackArgs { buffer, frames, .. }| {
+    let callback = move |args: pa::OutputStreamCallbackArgs<f32>| {
+        let pa::OutputStreamCallbackArgs { buffer, frames, .. } = args;
         let mut idx = 0;
         for _ in 0..frames {
             buffer[idx] = sine[left_phase];
@@ -19,32 +19,365 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+#[cfg(feature = "bindgen")]
+extern crate bindgen;
+#[cfg(feature = "vendored")]
+extern crate cc;
 extern crate pkg_config;
 
 use std::env;
 use std::fmt::Display;
-use std::path::Path;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 #[cfg(all(unix, not(target_os = "linux")))]
 use unix_platform as platform;
 
+/// The host APIs that may be selected with cargo features, each paired with the system library
+/// that a PortAudio build including it links against, if any.
+const HOST_API_FEATURES: &'static [(&'static str, Option<&'static str>)] = &[
+    ("alsa", Some("asound")),
+    ("jack", Some("jack")),
+    ("oss", None),
+    ("pulseaudio", Some("pulse")),
+];
+
+/// The PortAudio versions that added API to `portaudio.h`, each paired with an identifier that
+/// first appeared in the header in that version. Each version the header includes is exposed as a
+/// `portaudio_v<major>_<minor>` cfg.
+const HEADER_VERSIONS: &[((u32, u32), &str)] =
+    &[((19, 5), "Pa_GetVersionInfo"), ((19, 7), "paAudioIO")];
+
 fn main() {
     println!("cargo:rerun-if-changed=build.rs");
 
-    println!("cargo:rerun-if-env-changed=PORTAUDIO_ONLY_STATIC");
-    println!("cargo:rerun-if-env-changed=PORTAUDIO_CONFIGURE_EXTRA_ARGS");
+    let cfgs: Vec<String> = HEADER_VERSIONS
+        .iter()
+        .map(|&(version, _)| version_cfg(version))
+        .collect();
+    println!("cargo:rustc-check-cfg=cfg({})", cfgs.join(", "));
+
+    // The library is loaded at runtime, so there is nothing to link.
+    let host_apis = if feature_enabled("runtime-load") {
+        println!("cargo:rerun-if-env-changed=PORTAUDIO_INCLUDE_DIR");
+        print_metadata(None, None);
+        requested_host_apis()
+    } else {
+        find_or_build()
+    };
+    print_host_apis(&host_apis);
+}
+
+/// Link PortAudio, building it if necessary, and return the host APIs it includes.
+///
+/// The following environment variables control how the library is found:
+///
+/// - `PORTAUDIO_LIB_DIR`: link the library in this directory, e.g. `<prefix>/lib` of a custom
+///   install, using its `pkgconfig/portaudio-2.0.pc` file if present.
+/// - `PORTAUDIO_INCLUDE_DIR`: the directory containing `portaudio.h`, reported to dependent
+///   crates as `DEP_PORTAUDIO_INCLUDE`.
+/// - `PORTAUDIO_STATIC`: `1` to link statically or `0` to link dynamically, overriding the
+///   `static` feature.
+/// - `PORTAUDIO_NO_PKG_CONFIG`: don't use pkg-config. Without `PORTAUDIO_LIB_DIR`, the library
+///   is then linked by name from the linker's default search path.
+/// - `PORTAUDIO_ONLY_STATIC`: ignore any system library and build PortAudio from source.
+fn find_or_build() -> Vec<&'static str> {
+    if let Some(host_apis) = build_vendored() {
+        return host_apis;
+    }
+
+    for var in &[
+        "PORTAUDIO_ONLY_STATIC",
+        "PORTAUDIO_CONFIGURE_EXTRA_ARGS",
+        "PORTAUDIO_LIB_DIR",
+        "PORTAUDIO_INCLUDE_DIR",
+        "PORTAUDIO_STATIC",
+        "PORTAUDIO_NO_PKG_CONFIG",
+    ] {
+        println!("cargo:rerun-if-env-changed={}", var);
+    }
+
+    if let Some(lib_dir) = env::var_os("PORTAUDIO_LIB_DIR") {
+        return link_lib_dir(Path::new(&lib_dir));
+    }
+
     if env::var("PORTAUDIO_ONLY_STATIC").is_err() {
+        if !use_pkg_config() {
+            print_link_lib(link_static());
+            if link_static() {
+                print_static_deps(&requested_host_apis());
+            }
+            print_metadata(None, None);
+            return requested_host_apis();
+        }
+
         // If pkg-config finds a library on the system, we are done
-        if pkg_config::Config::new().atleast_version("19").find("portaudio-2.0").is_ok() {
-            return;
+        if let Ok(library) = pkg_config::Config::new()
+            .atleast_version("19")
+            .statik(link_static())
+            .probe("portaudio-2.0")
+        {
+            print_metadata(Some(&library.version), library.include_paths.first());
+            return system_host_apis("portaudio-2.0");
         }
     }
 
-    build();
+    build()
+}
+
+/// Whether to link PortAudio statically, as set by `PORTAUDIO_STATIC` or the `static` feature.
+fn link_static() -> bool {
+    match env::var("PORTAUDIO_STATIC") {
+        Ok(value) => value != "0",
+        Err(_) => feature_enabled("static"),
+    }
+}
+
+fn use_pkg_config() -> bool {
+    env::var_os("PORTAUDIO_NO_PKG_CONFIG").is_none()
+}
+
+/// Link the prebuilt library in `lib_dir`.
+fn link_lib_dir(lib_dir: &Path) -> Vec<&'static str> {
+    let pc_file = lib_dir.join("pkgconfig/portaudio-2.0.pc");
+    if use_pkg_config() && pc_file.exists() {
+        // The .pc file of a custom prefix describes its version, include path and, when linking
+        // statically, the libraries PortAudio itself depends on.
+        let pc_file = pc_file.to_str().unwrap();
+        let library = err_to_panic(
+            pkg_config::Config::new()
+                .statik(link_static())
+                .probe(pc_file),
+        );
+        print_metadata(Some(&library.version), library.include_paths.first());
+        return system_host_apis(pc_file);
+    }
+
+    println!("cargo:rustc-link-search=native={}", lib_dir.display());
+    print_link_lib(link_static());
+    let host_apis = requested_host_apis();
+    if link_static() {
+        print_static_deps(&host_apis);
+    }
+    // Assume the usual `<prefix>/lib` and `<prefix>/include` layout.
+    let include_dir = lib_dir
+        .parent()
+        .map(|prefix| prefix.join("include"))
+        .filter(|include_dir| include_dir.join("portaudio.h").exists());
+    print_metadata(None, include_dir.as_ref());
+    host_apis
+}
+
+fn print_link_lib(statik: bool) {
+    if statik {
+        println!("cargo:rustc-link-lib=static=portaudio");
+    } else {
+        println!("cargo:rustc-link-lib=portaudio");
+    }
+}
+
+/// Link the libraries that a static PortAudio with the given host APIs depends on, for when no
+/// .pc file is available to describe them.
+fn print_static_deps(host_apis: &[&str]) {
+    for &(feature, lib) in HOST_API_FEATURES {
+        if let Some(lib) = lib.filter(|_| host_apis.contains(&feature)) {
+            println!("cargo:rustc-link-lib={}", lib);
+        }
+    }
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    if target_os == "macos" {
+        for framework in &[
+            "AudioToolbox",
+            "AudioUnit",
+            "CoreAudio",
+            "CoreFoundation",
+            "CoreServices",
+        ] {
+            println!("cargo:rustc-link-lib=framework={}", framework);
+        }
+    } else if env::var("CARGO_CFG_TARGET_FAMILY").ok().as_deref() == Some("unix") {
+        println!("cargo:rustc-link-lib=m");
+        println!("cargo:rustc-link-lib=pthread");
+    }
+}
+
+/// The host APIs of the system library described by the pkg-config `package`.
+///
+/// These can't be chosen, so trust the features and detect any others from the libraries that a
+/// static link would require.
+fn system_host_apis(package: &str) -> Vec<&'static str> {
+    let mut host_apis = requested_host_apis();
+    if let Ok(library) = pkg_config::Config::new()
+        .cargo_metadata(false)
+        .statik(true)
+        .probe(package)
+    {
+        for api in detect_host_apis(&library.libs) {
+            if !host_apis.contains(&api) {
+                host_apis.push(api);
+            }
+        }
+    }
+    host_apis
+}
+
+/// Pass the library's version and include directory on to dependent crates as the
+/// `DEP_PORTAUDIO_VERSION` and `DEP_PORTAUDIO_INCLUDE` variables, where known.
+///
+/// `PORTAUDIO_INCLUDE_DIR` takes precedence over the given `include_dir`.
+fn print_metadata(version: Option<&str>, include_dir: Option<&PathBuf>) {
+    if let Some(version) = version {
+        println!("cargo:version={}", version);
+    }
+    let include_dir = env::var_os("PORTAUDIO_INCLUDE_DIR")
+        .map(PathBuf::from)
+        .or_else(|| include_dir.cloned());
+    if let Some(ref include_dir) = include_dir {
+        println!("cargo:include={}", include_dir.display());
+    }
+
+    // A system header in a default search path is usually omitted by pkg-config.
+    let header = include_dir
+        .into_iter()
+        .chain(system_include_dirs())
+        .map(|include_dir| include_dir.join("portaudio.h"))
+        .find(|header| header.exists());
+    let header_version = match header {
+        Some(ref header) => header_version(header),
+        None => version.and_then(parse_version),
+    };
+    if let Some(header_version) = header_version {
+        println!(
+            "cargo:header_version={}.{}",
+            header_version.0, header_version.1
+        );
+        for &(version, _) in HEADER_VERSIONS {
+            if version <= header_version {
+                println!("cargo:rustc-cfg={}", version_cfg(version));
+            }
+        }
+    }
+
+    generate_bindings(header.as_ref());
+}
+
+fn system_include_dirs() -> Vec<PathBuf> {
+    if env::var("CARGO_CFG_TARGET_FAMILY").ok().as_deref() == Some("unix") {
+        vec![
+            PathBuf::from("/usr/include"),
+            PathBuf::from("/usr/local/include"),
+        ]
+    } else {
+        vec![]
+    }
+}
+
+/// The latest of the `HEADER_VERSIONS` whose API the given `portaudio.h` declares.
+fn header_version(header: &Path) -> Option<(u32, u32)> {
+    let source = err_to_panic(fs::read_to_string(header));
+    HEADER_VERSIONS
+        .iter()
+        .rev()
+        .find(|&&(_, ident)| source.contains(ident))
+        .map(|&(version, _)| version)
+}
+
+/// The latest of the `HEADER_VERSIONS` up to a library version such as "19.7.0".
+///
+/// Distributions often report the version as just "19", which is of no use.
+fn parse_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.split('.').map(|part| part.parse::<u32>().ok());
+    let version = (parts.next()??, parts.next()??);
+    HEADER_VERSIONS
+        .iter()
+        .map(|&(header_version, _)| header_version)
+        .rev()
+        .find(|&header_version| header_version <= version)
 }
 
-fn build() {
+fn version_cfg((major, minor): (u32, u32)) -> String {
+    format!("portaudio_v{}_{}", major, minor)
+}
+
+/// Generate the bindings from the given `header` into `$OUT_DIR/portaudio.rs`, in place of the
+/// pregenerated `src/portaudio.rs`.
+///
+/// Generating the bindings requires libclang. See the bindgen documentation for how it is found.
+#[cfg(feature = "bindgen")]
+fn generate_bindings(header: Option<&PathBuf>) {
+    let header = header.unwrap_or_else(|| {
+        panic!(
+            "The `bindgen` feature requires portaudio.h. Set `PORTAUDIO_INCLUDE_DIR` to the \
+             directory containing it."
+        )
+    });
+    println!("cargo:rerun-if-changed={}", header.display());
+    let bindings = bindgen::Builder::default()
+        .header(header.to_str().unwrap())
+        .constified_enum("PaHostApiTypeId")
+        .constified_enum("PaErrorCode")
+        .blocklist_type("PaStreamCallbackResult")
+        .generate()
+        .expect("failed to generate the PortAudio bindings");
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    err_to_panic(bindings.write_to_file(out_dir.join("portaudio.rs")));
+}
+
+#[cfg(not(feature = "bindgen"))]
+fn generate_bindings(_: Option<&PathBuf>) {}
+
+/// Build the bundled sources if the `vendored` feature is enabled, returning the host APIs they
+/// include.
+#[cfg(feature = "vendored")]
+fn build_vendored() -> Option<Vec<&'static str>> {
+    Some(vendored::build())
+}
+
+#[cfg(not(feature = "vendored"))]
+fn build_vendored() -> Option<Vec<&'static str>> {
+    None
+}
+
+fn feature_enabled(feature: &str) -> bool {
+    // Cargo replaces the dashes of feature names with underscores.
+    let var = format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"));
+    env::var_os(var).is_some()
+}
+
+/// The host APIs whose features are enabled.
+fn requested_host_apis() -> Vec<&'static str> {
+    HOST_API_FEATURES
+        .iter()
+        .map(|&(feature, _)| feature)
+        .filter(|feature| feature_enabled(feature))
+        .collect()
+}
+
+/// The host APIs whose libraries are among the given `libs`.
+fn detect_host_apis(libs: &[String]) -> Vec<&'static str> {
+    HOST_API_FEATURES
+        .iter()
+        .filter(|&&(_, lib)| lib.map_or(false, |lib| libs.iter().any(|l| l == lib)))
+        .map(|&(feature, _)| feature)
+        .collect()
+}
+
+/// Emit a `portaudio_<host api>` cfg for each of the `host_apis` and pass them on to dependent
+/// crates as the `DEP_PORTAUDIO_HOST_APIS` variable.
+fn print_host_apis(host_apis: &[&str]) {
+    let cfgs: Vec<String> = HOST_API_FEATURES
+        .iter()
+        .map(|&(feature, _)| format!("portaudio_{}", feature))
+        .collect();
+    println!("cargo:rustc-check-cfg=cfg({})", cfgs.join(", "));
+    for api in host_apis {
+        println!("cargo:rustc-cfg=portaudio_{}", api);
+    }
+    println!("cargo:host_apis={}", host_apis.join(","));
+}
+
+fn build() -> Vec<&'static str> {
     // retrieve cargo deps out dir
     let out_dir_str = env::var("OUT_DIR").unwrap();
     let out_dir = Path::new(&out_dir_str);
@@ -55,7 +388,22 @@ fn build() {
         platform::build(out_dir);
     }
 
-    platform::print_libs(out_dir);
+    let libs = platform::print_libs(out_dir);
+    print_metadata(
+        Some(unix_platform::PORTAUDIO_VERSION),
+        Some(&out_dir.join("include")),
+    );
+
+    let requested = requested_host_apis();
+    if requested.is_empty() {
+        // configure enabled whichever host APIs it found.
+        detect_host_apis(&libs)
+    } else {
+        requested
+            .into_iter()
+            .filter(|&api| api != "pulseaudio")
+            .collect()
+    }
 }
 
 // Similar to unwrap, but panics on just the error value
@@ -63,7 +411,7 @@ fn build() {
 fn err_to_panic<T, E: Display>(result: Result<T, E>) -> T {
     match result {
         Ok(x) => x,
-        Err(e) => panic!("{}", e)
+        Err(e) => panic!("{}", e),
     }
 }
 
@@ -75,18 +423,223 @@ fn run(command: &mut Command) {
     }
 }
 
+/// Compiles the PortAudio sources bundled in the `portaudio` directory with the `cc` crate.
+///
+/// Unlike the fallback build, this requires no network access, shell or autotools. The host APIs
+/// compiled in on Linux are selected by the `alsa`, `jack` and `oss` features.
+#[cfg(feature = "vendored")]
+mod vendored {
+    use super::{feature_enabled, print_metadata};
+    use cc;
+    use std::env;
+    use std::path::{Path, PathBuf};
+
+    /// The sources shared by every platform, relative to the PortAudio root.
+    const COMMON_SOURCES: &'static [&'static str] = &[
+        "src/common/pa_allocation.c",
+        "src/common/pa_converters.c",
+        "src/common/pa_cpuload.c",
+        "src/common/pa_debugprint.c",
+        "src/common/pa_dither.c",
+        "src/common/pa_front.c",
+        "src/common/pa_process.c",
+        "src/common/pa_ringbuffer.c",
+        "src/common/pa_stream.c",
+        "src/common/pa_trace.c",
+    ];
+
+    /// A host API that may be compiled in.
+    struct HostApi {
+        /// The name of the cargo feature that selects the host API.
+        feature: &'static str,
+        /// The preprocessor symbol that enables the host API in `pa_*_hostapis.c`.
+        define: &'static str,
+        sources: &'static [&'static str],
+        /// Any extra preprocessor symbols required by the sources.
+        defines: &'static [&'static str],
+        /// The system libraries that must be linked.
+        libs: &'static [&'static str],
+    }
+
+    const LINUX_HOST_APIS: &'static [HostApi] = &[
+        HostApi {
+            feature: "alsa",
+            define: "PA_USE_ALSA",
+            sources: &["src/hostapi/alsa/pa_linux_alsa.c"],
+            defines: &[],
+            libs: &["asound"],
+        },
+        HostApi {
+            feature: "jack",
+            define: "PA_USE_JACK",
+            sources: &["src/hostapi/jack/pa_jack.c"],
+            defines: &[],
+            libs: &["jack"],
+        },
+        HostApi {
+            feature: "oss",
+            define: "PA_USE_OSS",
+            sources: &["src/hostapi/oss/pa_unix_oss.c"],
+            defines: &["HAVE_SYS_SOUNDCARD_H"],
+            libs: &[],
+        },
+    ];
+
+    pub fn build() -> Vec<&'static str> {
+        let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+        let root = manifest_dir.join("portaudio");
+        // The sources are always present in the published crate, but only in a git checkout once
+        // its submodules are initialised.
+        if !root.join("include/portaudio.h").exists() {
+            panic!(
+                "The PortAudio sources are missing from `{}`. Run `git submodule update --init`.",
+                root.display()
+            );
+        }
+        println!("cargo:rerun-if-changed={}", root.display());
+
+        let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap();
+        let target_family = env::var("CARGO_CFG_TARGET_FAMILY").unwrap_or_default();
+
+        let mut build = cc::Build::new();
+        build
+            .include(root.join("include"))
+            .include(root.join("src/common"))
+            .warnings(false);
+        add_sources(&mut build, &root, COMMON_SOURCES);
+        let mut host_apis = Vec::new();
+
+        if feature_enabled("pulseaudio") {
+            println!(
+                "cargo:warning=The bundled PortAudio v19.7.0 sources have no PulseAudio host API; \
+                 ignoring the `pulseaudio` feature"
+            );
+        }
+
+        if target_os == "windows" {
+            build
+                .include(root.join("src/os/win"))
+                .define("PA_USE_WMME", "1");
+            add_sources(
+                &mut build,
+                &root,
+                &[
+                    "src/os/win/pa_win_coinitialize.c",
+                    "src/os/win/pa_win_hostapis.c",
+                    "src/os/win/pa_win_util.c",
+                    "src/os/win/pa_win_waveformat.c",
+                    "src/os/win/pa_x86_plain_converters.c",
+                    "src/hostapi/wmme/pa_win_wmme.c",
+                ],
+            );
+            for lib in &["winmm", "ole32", "uuid"] {
+                println!("cargo:rustc-link-lib={}", lib);
+            }
+        } else if target_family == "unix" {
+            build
+                .include(root.join("src/os/unix"))
+                .define("HAVE_CLOCK_GETTIME", "1")
+                .define("HAVE_NANOSLEEP", "1");
+            add_sources(
+                &mut build,
+                &root,
+                &[
+                    "src/os/unix/pa_unix_hostapis.c",
+                    "src/os/unix/pa_unix_util.c",
+                ],
+            );
+            if target_os == "macos" {
+                build
+                    .include(root.join("src/hostapi/coreaudio"))
+                    .define("PA_USE_COREAUDIO", "1");
+                add_sources(
+                    &mut build,
+                    &root,
+                    &[
+                        "src/hostapi/coreaudio/pa_mac_core.c",
+                        "src/hostapi/coreaudio/pa_mac_core_blocking.c",
+                        "src/hostapi/coreaudio/pa_mac_core_utilities.c",
+                    ],
+                );
+                for framework in &[
+                    "AudioToolbox",
+                    "AudioUnit",
+                    "CoreAudio",
+                    "CoreFoundation",
+                    "CoreServices",
+                ] {
+                    println!("cargo:rustc-link-lib=framework={}", framework);
+                }
+            } else {
+                host_apis = add_host_apis(&mut build, &root, &target_os);
+            }
+            println!("cargo:rustc-link-lib=pthread");
+            println!("cargo:rustc-link-lib=m");
+        } else {
+            panic!(
+                "The `vendored` feature does not support the `{}` target",
+                target_os
+            );
+        }
+
+        build.compile("portaudio");
+        print_metadata(Some("19.7.0"), Some(&root.join("include")));
+        host_apis
+    }
+
+    fn add_sources(build: &mut cc::Build, root: &Path, sources: &[&str]) {
+        for source in sources {
+            build.file(root.join(source));
+        }
+    }
+
+    /// Compile in the host APIs selected by the cargo features, defaulting to ALSA on Linux, and
+    /// return their names.
+    fn add_host_apis(build: &mut cc::Build, root: &Path, target_os: &str) -> Vec<&'static str> {
+        let mut selected: Vec<&HostApi> = LINUX_HOST_APIS
+            .iter()
+            .filter(|api| feature_enabled(api.feature))
+            .collect();
+        if selected.is_empty() {
+            let default = if target_os == "linux" { "alsa" } else { "oss" };
+            println!(
+                "cargo:warning=No host API feature is enabled for the vendored PortAudio build; \
+                 defaulting to `{}`",
+                default
+            );
+            selected = LINUX_HOST_APIS
+                .iter()
+                .filter(|api| api.feature == default)
+                .collect();
+        }
+        for api in &selected {
+            build.define(api.define, "1");
+            for define in api.defines {
+                build.define(define, "1");
+            }
+            add_sources(build, root, api.sources);
+            for lib in api.libs {
+                println!("cargo:rustc-link-lib={}", lib);
+            }
+        }
+        selected.iter().map(|api| api.feature).collect()
+    }
+}
+
 #[allow(dead_code)]
 mod unix_platform {
-    use std::process::Command;
     use std::path::Path;
+    use std::process::Command;
 
     use std::env;
 
-    use super::{err_to_panic, run};
+    use super::{err_to_panic, requested_host_apis, run, HOST_API_FEATURES};
 
-    pub const PORTAUDIO_URL: &'static str = "https://files.portaudio.com/archives/pa_stable_v190700_20210406.tgz";
+    pub const PORTAUDIO_URL: &'static str =
+        "https://files.portaudio.com/archives/pa_stable_v190700_20210406.tgz";
     pub const PORTAUDIO_TAR: &'static str = "pa_stable_v190700_20210406.tgz";
     pub const PORTAUDIO_FOLDER: &'static str = "portaudio";
+    pub const PORTAUDIO_VERSION: &'static str = "19.7.0";
 
     pub fn download() {
         run(Command::new("curl").arg(PORTAUDIO_URL).arg("-O"));
@@ -101,10 +654,10 @@ mod unix_platform {
 
         // run portaudio autoconf
         let mut cmd = Command::new("./configure");
-        cmd
-            .args(&["--disable-shared", "--enable-static"]) // Only build static lib
+        cmd.args(&["--disable-shared", "--enable-static"]) // Only build static lib
             .args(&["--prefix", out_dir.to_str().unwrap()]) // Install on the outdir
             .arg("--with-pic"); // Build position-independent code (required by Rust)
+        cmd.args(host_api_args());
         if let Ok(extra_args) = env::var("PORTAUDIO_CONFIGURE_EXTRA_ARGS") {
             cmd.args(extra_args.split(" "));
         }
@@ -120,24 +673,56 @@ mod unix_platform {
         err_to_panic(env::set_current_dir(".."));
 
         // cleaning portaudio sources
-        run(Command::new("rm").arg("-rf")
+        run(Command::new("rm")
+            .arg("-rf")
             .args(&[PORTAUDIO_TAR, PORTAUDIO_FOLDER]));
     }
 
-    pub fn print_libs(out_dir: &Path) {
+    /// The configure arguments enabling exactly the requested host APIs, or none to let
+    /// configure enable those it finds if no host API features are enabled.
+    fn host_api_args() -> Vec<String> {
+        let requested = requested_host_apis();
+        if requested.is_empty() {
+            return Vec::new();
+        }
+        if requested.contains(&"pulseaudio") {
+            println!(
+                "cargo:warning=PortAudio v19.7.0 has no PulseAudio host API; ignoring the \
+                 `pulseaudio` feature"
+            );
+        }
+        HOST_API_FEATURES
+            .iter()
+            .map(|&(feature, _)| feature)
+            .filter(|&feature| feature != "pulseaudio")
+            .map(|feature| {
+                if requested.contains(&feature) {
+                    format!("--with-{}", feature)
+                } else {
+                    format!("--without-{}", feature)
+                }
+            })
+            .collect()
+    }
+
+    pub fn print_libs(out_dir: &Path) -> Vec<String> {
         let out_str = out_dir.to_str().unwrap();
-        println!("cargo:rustc-flags=-L native={}/lib -l static=portaudio", out_str);
+        println!(
+            "cargo:rustc-flags=-L native={}/lib -l static=portaudio",
+            out_str
+        );
+        Vec::new()
     }
 }
 
 #[cfg(target_os = "linux")]
 mod platform {
-    use pkg_config;
-    use std::process::Command;
     use super::unix_platform;
+    use pkg_config;
     use std::path::Path;
+    use std::process::Command;
 
-    use super::{run, err_to_panic};
+    use super::{err_to_panic, run};
 
     pub fn download() {
         run(Command::new("wget").arg(unix_platform::PORTAUDIO_URL));
@@ -147,11 +732,16 @@ mod platform {
         unix_platform::build(out_dir);
     }
 
-    pub fn print_libs(out_dir: &Path) {
+    pub fn print_libs(out_dir: &Path) -> Vec<String> {
         let portaudio_pc_file = out_dir.join("lib/pkgconfig/portaudio-2.0.pc");
         let portaudio_pc_file = portaudio_pc_file.to_str().unwrap();
 
-        err_to_panic(pkg_config::Config::new().statik(true).find(portaudio_pc_file));
+        err_to_panic(
+            pkg_config::Config::new()
+                .statik(true)
+                .find(portaudio_pc_file),
+        )
+        .libs
     }
 }
 
@@ -173,7 +763,8 @@ mod platform {
         print_lib_url();
     }
 
-    pub fn print_libs(_: &Path) {
+    pub fn print_libs(_: &Path) -> Vec<String> {
         print_lib_url();
+        Vec::new()
     }
 }
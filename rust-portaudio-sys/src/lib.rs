@@ -1,24 +1,87 @@
-// Command used to generate portaudio.rs:
+// Command used to generate portaudio.rs from the PortAudio v19.7.0 portaudio.h:
 /* bindgen portaudio.h -o portaudio.rs \
                        --constified-enum PaHostApiTypeId \
                        --constified-enum PaErrorCode \
-                       --blacklist-type PaStreamCallbackResult
+                       --blocklist-type PaStreamCallbackResult
 */
+// The `bindgen` feature instead generates the bindings at build time from the header of the
+// PortAudio being linked, with the same options. See `generate_bindings` in build.rs.
 
-#[cfg(any(target_os = "macos", target_os = "linux", target_os = "windows"))]
+#[cfg(all(
+    any(target_os = "macos", target_os = "linux", target_os = "windows"),
+    not(feature = "runtime-load")
+))]
 mod c_library {
     #[link(name = "portaudio")]
     extern "C" {}
 }
 
+#[cfg(feature = "runtime-load")]
+extern crate libc;
+
+#[cfg(all(feature = "runtime-load", not(unix)))]
+compile_error!("the `runtime-load` feature requires `dlopen` and so is only supported on unix");
+
+// With `runtime-load`, the linked functions are shadowed by the wrappers and never used.
+#[cfg(not(feature = "bindgen"))]
+#[cfg_attr(feature = "runtime-load", allow(dead_code))]
 mod portaudio;
+#[cfg(feature = "bindgen")]
+#[cfg_attr(feature = "runtime-load", allow(dead_code))]
+#[allow(non_upper_case_globals, non_camel_case_types, non_snake_case)]
+mod portaudio {
+    include!(concat!(env!("OUT_DIR"), "/portaudio.rs"));
+}
+// Bindings to the ALSA host API extensions, generated from pa_linux_alsa.h with:
+/* bindgen pa_linux_alsa.h -o pa_linux_alsa.rs \
+                           --allowlist-file '.*pa_linux_alsa.h' \
+                           --raw-line 'use portaudio::*;'
+*/
+// With `runtime-load`, these are resolved if the loaded library has them.
+#[cfg(any(portaudio_alsa, feature = "runtime-load"))]
+#[cfg_attr(feature = "runtime-load", allow(dead_code))]
+mod pa_linux_alsa;
+// Bindings to the JACK host API extensions, generated from pa_jack.h with:
+/* bindgen pa_jack.h -o pa_jack.rs \
+                     --allowlist-file '.*pa_jack.h' \
+                     --raw-line 'use portaudio::*;'
+*/
+#[cfg(any(portaudio_jack, feature = "runtime-load"))]
+#[cfg_attr(feature = "runtime-load", allow(dead_code))]
+mod pa_jack;
+#[cfg(feature = "runtime-load")]
+#[macro_use]
+pub mod runtime;
 
+// The module only declares functions, which the wrappers replace with `runtime-load`.
+#[cfg(all(portaudio_jack, not(feature = "runtime-load")))]
+pub use pa_jack::*;
+#[cfg(any(portaudio_alsa, feature = "runtime-load"))]
+pub use pa_linux_alsa::*;
 pub use portaudio::*;
+#[cfg(feature = "runtime-load")]
+pub use runtime::{load, require, LoadError};
+
+// The wrappers take precedence over the glob-imported linked functions of the same names.
+#[cfg(feature = "runtime-load")]
+with_functions!(define_wrappers);
 
 pub const PA_NO_DEVICE: PaDeviceIndex = -1;
 
+// Host API type ids missing from bindings generated from an older header, or added after v19.7.0.
+#[cfg(all(feature = "bindgen", not(portaudio_v19_7)))]
+#[allow(non_upper_case_globals)]
+pub const PaHostApiTypeId_paAudioIO: PaHostApiTypeId = 15;
+#[allow(non_upper_case_globals)]
+pub const PaHostApiTypeId_paPulseAudio: PaHostApiTypeId = 16;
+#[allow(non_upper_case_globals)]
+pub const PaHostApiTypeId_paSndio: PaHostApiTypeId = 17;
+
+// portaudio.h defines the following flags as macros that cast to their typedef, which bindgen
+// can't translate, so they are repeated here with the generated types.
+
 // Sample format
-pub type SampleFormat = ::std::os::raw::c_ulong;
+pub type SampleFormat = PaSampleFormat;
 pub const PA_FLOAT_32: SampleFormat = 0x00000001;
 pub const PA_INT_32: SampleFormat = 0x00000002;
 pub const PA_INT_24: SampleFormat = 0x00000004;
@@ -29,7 +92,7 @@ pub const PA_CUSTOM_FORMAT: SampleFormat = 0x00010000;
 pub const PA_NON_INTERLEAVED: SampleFormat = 0x80000000;
 
 // Stream flags
-pub type StreamFlags = ::std::os::raw::c_ulong;
+pub type StreamFlags = PaStreamFlags;
 pub const PA_NO_FLAG: StreamFlags = 0;
 pub const PA_CLIP_OFF: StreamFlags = 0x00000001;
 pub const PA_DITHER_OFF: StreamFlags = 0x00000002;
@@ -37,8 +100,8 @@ pub const PA_NEVER_DROP_INPUT: StreamFlags = 0x00000004;
 pub const PA_PRIME_OUTPUT_BUFFERS_USING_STREAM_CALLBACK: StreamFlags = 0x00000008;
 pub const PA_PLATFORM_SPECIFIC_FLAGS: StreamFlags = 0xFFFF0000;
 
-// Stream callback falgs.
-pub type StreamCallbackFlags = ::std::os::raw::c_ulong;
+// Stream callback flags.
+pub type StreamCallbackFlags = PaStreamCallbackFlags;
 pub const INPUT_UNDERFLOW: StreamCallbackFlags = 0x00000001;
 pub const INPUT_OVERFLOW: StreamCallbackFlags = 0x00000002;
 pub const OUTPUT_UNDERFLOW: StreamCallbackFlags = 0x00000004;
@@ -53,6 +116,10 @@ pub fn c_str_to_str<'a>(
 }
 
 /// A function to convert Rust strings to C strings
+///
+/// The returned pointer is neither NUL-terminated nor does it own the string data, so it must not
+/// be read by C code.
+#[deprecated(note = "the returned string is not NUL-terminated; use `std::ffi::CString` instead")]
 pub fn str_to_c_str(rust_str: &str) -> *const std::os::raw::c_char {
     rust_str.as_ptr() as *const _
 }
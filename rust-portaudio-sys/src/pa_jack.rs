@@ -0,0 +1,28 @@
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+
+/* automatically generated by rust-bindgen 0.69.5 */
+
+use portaudio::*;
+
+extern "C" {
+    #[doc = " Set the JACK client name."]
+    #[doc = ""]
+    #[doc = " During Pa_Initialize, When PA JACK connects as a client of the JACK server, it requests a certain"]
+    #[doc = " name, which is for instance prepended to port names. By default this name is \"PortAudio\". The"]
+    #[doc = " JACK server may append a suffix to the client name, in order to avoid clashes among clients that"]
+    #[doc = " try to connect with the same name (e.g., different PA JACK clients)."]
+    #[doc = ""]
+    #[doc = " This function must be called before Pa_Initialize, otherwise it won't have any effect. Note that"]
+    #[doc = " the string is not copied, but instead referenced directly, so it must not be freed for as long as"]
+    #[doc = " PA might need it."]
+    #[doc = " @sa PaJack_GetClientName"]
+    pub fn PaJack_SetClientName(name: *const ::std::os::raw::c_char) -> PaError;
+}
+extern "C" {
+    #[doc = " Get the JACK client name used by PA JACK."]
+    #[doc = ""]
+    #[doc = " The caller is responsible for releasing the returned string."]
+    pub fn PaJack_GetClientName(clientName: *mut *const ::std::os::raw::c_char) -> PaError;
+}
@@ -0,0 +1,109 @@
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+
+/* automatically generated by rust-bindgen 0.69.5 */
+
+use portaudio::*;
+
+#[doc = " Alsa-specific stream information, passed as the hostApiSpecificStreamInfo of a"]
+#[doc = " PaStreamParameters."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct PaAlsaStreamInfo {
+    pub size: ::std::os::raw::c_ulong,
+    pub hostApiType: PaHostApiTypeId,
+    pub version: ::std::os::raw::c_ulong,
+    pub deviceString: *const ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_PaAlsaStreamInfo() {
+    const UNINIT: ::std::mem::MaybeUninit<PaAlsaStreamInfo> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<PaAlsaStreamInfo>(),
+        32usize,
+        concat!("Size of: ", stringify!(PaAlsaStreamInfo))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<PaAlsaStreamInfo>(),
+        8usize,
+        concat!("Alignment of ", stringify!(PaAlsaStreamInfo))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).size) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(PaAlsaStreamInfo),
+            "::",
+            stringify!(size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hostApiType) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(PaAlsaStreamInfo),
+            "::",
+            stringify!(hostApiType)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).version) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(PaAlsaStreamInfo),
+            "::",
+            stringify!(version)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).deviceString) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(PaAlsaStreamInfo),
+            "::",
+            stringify!(deviceString)
+        )
+    );
+}
+extern "C" {
+    #[doc = " Initialize host API specific structure, call this before setting relevant attributes."]
+    pub fn PaAlsa_InitializeStreamInfo(info: *mut PaAlsaStreamInfo);
+}
+extern "C" {
+    #[doc = " Instruct whether to enable real-time priority when starting the audio thread."]
+    #[doc = ""]
+    #[doc = " If this is turned on by the stream is started, the audio callback thread will be created"]
+    #[doc = " with the FIFO scheduling policy, which is suitable for realtime operation."]
+    pub fn PaAlsa_EnableRealtimeScheduling(s: *mut PaStream, enable: ::std::os::raw::c_int);
+}
+extern "C" {
+    #[doc = " Get the ALSA-lib card index of this stream's input device."]
+    pub fn PaAlsa_GetStreamInputCard(s: *mut PaStream, card: *mut ::std::os::raw::c_int)
+        -> PaError;
+}
+extern "C" {
+    #[doc = " Get the ALSA-lib card index of this stream's output device."]
+    pub fn PaAlsa_GetStreamOutputCard(
+        s: *mut PaStream,
+        card: *mut ::std::os::raw::c_int,
+    ) -> PaError;
+}
+extern "C" {
+    #[doc = " Set the number of periods (buffer fragments) to configure devices with."]
+    #[doc = ""]
+    #[doc = " By default the number of periods is 4, this is the lowest number of periods that works"]
+    #[doc = " well on the author's soundcard."]
+    #[doc = " @param numPeriods The number of periods."]
+    pub fn PaAlsa_SetNumPeriods(numPeriods: ::std::os::raw::c_int) -> PaError;
+}
+extern "C" {
+    #[doc = " Set the maximum number of times to retry opening busy device (sleeping for a"]
+    #[doc = " short interval inbetween)."]
+    pub fn PaAlsa_SetRetriesBusy(retries: ::std::os::raw::c_int) -> PaError;
+}
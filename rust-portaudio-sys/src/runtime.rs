@@ -0,0 +1,317 @@
+//! Resolves the PortAudio API at runtime through `dlopen` and `dlsym` rather than linking
+//! against the library at build time, enabled by the `runtime-load` feature.
+//!
+//! Each `Pa_*` function at the crate root is a wrapper with the same signature that
+//! calls through a table of symbols resolved on first use. Call [**load**](./fn.load.html) first to
+//! handle a missing library or symbol gracefully; calling any other function when the library
+//! can't be loaded panics. The functions of host API extensions, such as `PaAlsa_*`, may be absent
+//! even when the library loads, so check for them with [**require**](./fn.require.html) first.
+
+use libc::{self, c_char, c_void};
+use std::ffi::CStr;
+use std::fmt;
+use std::mem;
+use std::sync::OnceLock;
+
+use pa_linux_alsa::*;
+use portaudio::*;
+
+/// The names under which the PortAudio shared library is searched for, in order.
+#[cfg(target_os = "macos")]
+const LIBRARY_NAMES: &[&str] = &["libportaudio.2.dylib", "libportaudio.dylib"];
+#[cfg(not(target_os = "macos"))]
+const LIBRARY_NAMES: &[&str] = &["libportaudio.so.2", "libportaudio.so"];
+
+/// The reasons that the PortAudio shared library may fail to load.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LoadError {
+    /// None of the library names could be opened. Holds the message from `dlerror` for the last.
+    LibraryNotFound(String),
+    /// The library lacks the named symbol.
+    MissingSymbol(&'static str),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LoadError::LibraryNotFound(ref message) => {
+                write!(f, "The PortAudio library could not be loaded: {}", message)
+            }
+            LoadError::MissingSymbol(symbol) => {
+                write!(f, "The PortAudio library lacks the `{}` symbol", symbol)
+            }
+        }
+    }
+}
+
+impl ::std::error::Error for LoadError {}
+
+static LIBRARY: OnceLock<Result<Library, LoadError>> = OnceLock::new();
+
+/// Load the PortAudio shared library and resolve its symbols, if not already done.
+///
+/// The library stays loaded for the remainder of the process.
+pub fn load() -> Result<(), LoadError> {
+    library().map(|_| ())
+}
+
+fn library() -> Result<&'static Library, LoadError> {
+    LIBRARY
+        .get_or_init(|| unsafe { Library::open() })
+        .as_ref()
+        .map_err(|err| err.clone())
+}
+
+/// The resolved API, loading the library if necessary.
+///
+/// Panics if the library can't be loaded.
+pub fn functions() -> &'static Functions {
+    match try_functions() {
+        Ok(functions) => functions,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// The resolved API, loading the library if necessary, or the reason it can't be loaded.
+pub fn try_functions() -> Result<&'static Functions, LoadError> {
+    library().map(|library| &library.functions)
+}
+
+/// Load the library if necessary and check that it has the named symbol, which the wrapper of an
+/// optional function would otherwise panic without.
+pub fn require(symbol: &'static str) -> Result<(), LoadError> {
+    if try_functions()?.has(symbol) {
+        Ok(())
+    } else {
+        Err(LoadError::MissingSymbol(symbol))
+    }
+}
+
+/// Open the library under the first of the `LIBRARY_NAMES` that succeeds.
+unsafe fn dlopen() -> Result<*mut c_void, LoadError> {
+    let mut message = String::new();
+    for name in LIBRARY_NAMES {
+        let c_name = ::std::ffi::CString::new(*name).unwrap();
+        let handle = libc::dlopen(c_name.as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL);
+        if !handle.is_null() {
+            return Ok(handle);
+        }
+        let error = libc::dlerror();
+        message = if error.is_null() {
+            format!("{} could not be opened", name)
+        } else {
+            CStr::from_ptr(error).to_string_lossy().into_owned()
+        };
+    }
+    Err(LoadError::LibraryNotFound(message))
+}
+
+/// Resolve the symbol with the given NUL-terminated `name`.
+unsafe fn dlsym(handle: *mut c_void, name: &'static str) -> Option<*mut c_void> {
+    let symbol = libc::dlsym(handle, name.as_ptr() as *const c_char);
+    if symbol.is_null() {
+        None
+    } else {
+        Some(symbol)
+    }
+}
+
+/// The name of a symbol without its NUL terminator.
+fn symbol_name(name: &'static str) -> &'static str {
+    &name[..name.len() - 1]
+}
+
+/// Invoke the macro `$m` with the signatures of the PortAudio API.
+macro_rules! with_functions {
+    ($m:ident) => {
+        $m! {
+            required {
+                fn Pa_GetVersion() -> ::std::os::raw::c_int;
+                fn Pa_GetVersionText() -> *const ::std::os::raw::c_char;
+                fn Pa_GetErrorText(errorCode: PaError) -> *const ::std::os::raw::c_char;
+                fn Pa_Initialize() -> PaError;
+                fn Pa_Terminate() -> PaError;
+                fn Pa_GetHostApiCount() -> PaHostApiIndex;
+                fn Pa_GetDefaultHostApi() -> PaHostApiIndex;
+                fn Pa_GetHostApiInfo(hostApi: PaHostApiIndex) -> *const PaHostApiInfo;
+                fn Pa_HostApiTypeIdToHostApiIndex(type_: PaHostApiTypeId) -> PaHostApiIndex;
+                fn Pa_HostApiDeviceIndexToDeviceIndex(
+                    hostApi: PaHostApiIndex,
+                    hostApiDeviceIndex: ::std::os::raw::c_int
+                ) -> PaDeviceIndex;
+                fn Pa_GetLastHostErrorInfo() -> *const PaHostErrorInfo;
+                fn Pa_GetDeviceCount() -> PaDeviceIndex;
+                fn Pa_GetDefaultInputDevice() -> PaDeviceIndex;
+                fn Pa_GetDefaultOutputDevice() -> PaDeviceIndex;
+                fn Pa_GetDeviceInfo(device: PaDeviceIndex) -> *const PaDeviceInfo;
+                fn Pa_IsFormatSupported(
+                    inputParameters: *const PaStreamParameters,
+                    outputParameters: *const PaStreamParameters,
+                    sampleRate: f64
+                ) -> PaError;
+                fn Pa_OpenStream(
+                    stream: *mut *mut PaStream,
+                    inputParameters: *const PaStreamParameters,
+                    outputParameters: *const PaStreamParameters,
+                    sampleRate: f64,
+                    framesPerBuffer: ::std::os::raw::c_ulong,
+                    streamFlags: PaStreamFlags,
+                    streamCallback: PaStreamCallback,
+                    userData: *mut ::std::os::raw::c_void
+                ) -> PaError;
+                fn Pa_OpenDefaultStream(
+                    stream: *mut *mut PaStream,
+                    numInputChannels: ::std::os::raw::c_int,
+                    numOutputChannels: ::std::os::raw::c_int,
+                    sampleFormat: PaSampleFormat,
+                    sampleRate: f64,
+                    framesPerBuffer: ::std::os::raw::c_ulong,
+                    streamCallback: PaStreamCallback,
+                    userData: *mut ::std::os::raw::c_void
+                ) -> PaError;
+                fn Pa_CloseStream(stream: *mut PaStream) -> PaError;
+                fn Pa_SetStreamFinishedCallback(
+                    stream: *mut PaStream,
+                    streamFinishedCallback: PaStreamFinishedCallback
+                ) -> PaError;
+                fn Pa_StartStream(stream: *mut PaStream) -> PaError;
+                fn Pa_StopStream(stream: *mut PaStream) -> PaError;
+                fn Pa_AbortStream(stream: *mut PaStream) -> PaError;
+                fn Pa_IsStreamStopped(stream: *mut PaStream) -> PaError;
+                fn Pa_IsStreamActive(stream: *mut PaStream) -> PaError;
+                fn Pa_GetStreamInfo(stream: *mut PaStream) -> *const PaStreamInfo;
+                fn Pa_GetStreamTime(stream: *mut PaStream) -> PaTime;
+                fn Pa_GetStreamCpuLoad(stream: *mut PaStream) -> f64;
+                fn Pa_ReadStream(
+                    stream: *mut PaStream,
+                    buffer: *mut ::std::os::raw::c_void,
+                    frames: ::std::os::raw::c_ulong
+                ) -> PaError;
+                fn Pa_WriteStream(
+                    stream: *mut PaStream,
+                    buffer: *const ::std::os::raw::c_void,
+                    frames: ::std::os::raw::c_ulong
+                ) -> PaError;
+                fn Pa_GetStreamReadAvailable(stream: *mut PaStream) -> ::std::os::raw::c_long;
+                fn Pa_GetStreamWriteAvailable(stream: *mut PaStream) -> ::std::os::raw::c_long;
+                fn Pa_GetSampleSize(format: PaSampleFormat) -> PaError;
+                fn Pa_Sleep(msec: ::std::os::raw::c_long) -> ();
+            }
+            // Symbols missing from older versions of the library, or from builds without the
+            // host API they belong to.
+            optional {
+                fn Pa_GetVersionInfo() -> *const PaVersionInfo;
+                fn PaAlsa_InitializeStreamInfo(info: *mut PaAlsaStreamInfo) -> ();
+                fn PaAlsa_EnableRealtimeScheduling(
+                    s: *mut PaStream,
+                    enable: ::std::os::raw::c_int
+                ) -> ();
+                fn PaAlsa_GetStreamInputCard(
+                    s: *mut PaStream,
+                    card: *mut ::std::os::raw::c_int
+                ) -> PaError;
+                fn PaAlsa_GetStreamOutputCard(
+                    s: *mut PaStream,
+                    card: *mut ::std::os::raw::c_int
+                ) -> PaError;
+                fn PaAlsa_SetNumPeriods(numPeriods: ::std::os::raw::c_int) -> PaError;
+                fn PaAlsa_SetRetriesBusy(retries: ::std::os::raw::c_int) -> PaError;
+                fn PaJack_SetClientName(name: *const ::std::os::raw::c_char) -> PaError;
+                fn PaJack_GetClientName(
+                    clientName: *mut *const ::std::os::raw::c_char
+                ) -> PaError;
+            }
+        }
+    };
+}
+
+/// Define the table of resolved functions and the **Library** that holds it.
+macro_rules! define_library {
+    (
+        required {
+            $(fn $name:ident($($arg:ident: $arg_ty:ty),*) -> $ret:ty;)*
+        }
+        optional {
+            $(fn $opt_name:ident($($opt_arg:ident: $opt_arg_ty:ty),*) -> $opt_ret:ty;)*
+        }
+    ) => {
+        /// The resolved PortAudio API.
+        #[allow(non_snake_case)]
+        pub struct Functions {
+            $(pub $name: unsafe extern "C" fn($($arg_ty),*) -> $ret,)*
+            $(pub $opt_name: Option<unsafe extern "C" fn($($opt_arg_ty),*) -> $opt_ret>,)*
+        }
+
+        impl Functions {
+            /// Whether the library has the named symbol.
+            pub fn has(&self, symbol: &str) -> bool {
+                match symbol {
+                    $(stringify!($name) => true,)*
+                    $(stringify!($opt_name) => self.$opt_name.is_some(),)*
+                    _ => false,
+                }
+            }
+        }
+
+        struct Library {
+            functions: Functions,
+        }
+
+        // The table only holds function pointers into a library that is never unloaded.
+        unsafe impl Send for Library {}
+        unsafe impl Sync for Library {}
+
+        impl Library {
+            unsafe fn open() -> Result<Self, LoadError> {
+                let handle = dlopen()?;
+                let functions = Functions {
+                    $($name: {
+                        let name = concat!(stringify!($name), "\0");
+                        match dlsym(handle, name) {
+                            Some(symbol) => mem::transmute(symbol),
+                            None => return Err(LoadError::MissingSymbol(symbol_name(name))),
+                        }
+                    },)*
+                    $($opt_name: {
+                        let name = concat!(stringify!($opt_name), "\0");
+                        dlsym(handle, name).map(|symbol| mem::transmute(symbol))
+                    },)*
+                };
+                Ok(Library { functions })
+            }
+        }
+    };
+}
+
+/// Define a wrapper for each function that calls through the table, to be invoked at the crate
+/// root where the wrappers shadow the linked functions of the same names.
+macro_rules! define_wrappers {
+    (
+        required {
+            $(fn $name:ident($($arg:ident: $arg_ty:ty),*) -> $ret:ty;)*
+        }
+        optional {
+            $(fn $opt_name:ident($($opt_arg:ident: $opt_arg_ty:ty),*) -> $opt_ret:ty;)*
+        }
+    ) => {
+        $(
+            #[allow(non_snake_case)]
+            pub unsafe fn $name($($arg: $arg_ty),*) -> $ret {
+                (runtime::functions().$name)($($arg),*)
+            }
+        )*
+
+        $(
+            /// Panics if the loaded library lacks the symbol. See **require**.
+            #[allow(non_snake_case)]
+            pub unsafe fn $opt_name($($opt_arg: $opt_arg_ty),*) -> $opt_ret {
+                match runtime::functions().$opt_name {
+                    Some(f) => f($($opt_arg),*),
+                    None => panic!("{}", runtime::LoadError::MissingSymbol(stringify!($opt_name))),
+                }
+            }
+        )*
+    };
+}
+
+with_functions!(define_library);
@@ -0,0 +1,36 @@
+//! Checks that the `runtime-load` feature links nothing at build time.
+
+#![cfg(feature = "runtime-load")]
+
+extern crate portaudio_sys as ffi;
+
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn build_script_links_no_library() {
+    // Cargo records the build script's output beside its `OUT_DIR`.
+    let output = Path::new(env!("OUT_DIR")).parent().unwrap().join("output");
+    let output = fs::read_to_string(output).unwrap();
+    let link_libs: Vec<&str> = output
+        .lines()
+        .filter(|line| {
+            line.starts_with("cargo:rustc-link-lib") || line.starts_with("cargo::rustc-link-lib")
+        })
+        .collect();
+    assert!(link_libs.is_empty(), "{:?}", link_libs);
+}
+
+// Skipped when no PortAudio installation can be loaded.
+#[test]
+fn require_reports_missing_symbols() {
+    if ffi::load().is_err() {
+        return;
+    }
+    assert_eq!(ffi::require("Pa_Initialize"), Ok(()));
+    let symbol = "PaNone_NotAFunction";
+    assert_eq!(
+        ffi::require(symbol),
+        Err(ffi::LoadError::MissingSymbol(symbol))
+    );
+}
@@ -0,0 +1,132 @@
+//! Options for constructing a **PortAudio** instance.
+//!
+//! The primary type of interest is [**Builder**](./struct.Builder.html), produced by the
+//! [**PortAudio::builder**](../struct.PortAudio.html#method.builder) method.
+
+use std::fmt;
+
+use super::error::Error;
+use super::types::{HostApiTypeId, VersionInfo};
+use super::PortAudio;
+
+/// Constructs a **PortAudio** instance with options beyond those of **PortAudio::new**.
+///
+/// ```no_run
+/// extern crate portaudio as pa;
+///
+/// # fn main() {
+/// let pa = pa::PortAudio::builder()
+///     .host_apis(vec![pa::HostApiTypeId::JACK, pa::HostApiTypeId::ALSA])
+///     .default_host_api(pa::HostApiTypeId::JACK)
+///     .build()
+///     .unwrap();
+/// let device = pa.default_output_device().unwrap();
+/// # }
+/// ```
+#[derive(Default)]
+pub struct Builder {
+    host_apis: Option<Vec<HostApiTypeId>>,
+    default_host_api: Option<HostApiTypeId>,
+    min_version: Option<VersionInfo>,
+    pre_init: Vec<Box<dyn FnOnce() -> Result<(), Error>>>,
+}
+
+impl Builder {
+    /// A builder with the options of **PortAudio::new**.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only consider the given host APIs.
+    ///
+    /// The devices and host APIs of any others are skipped by **PortAudio::devices** and
+    /// **PortAudio::host_apis**, **PortAudio::host_api** returns an `ErrorKind::HostApiNotFound`
+    /// error for them and they are never chosen as the default host API. Methods taking a
+    /// **DeviceIndex** or **HostApiIndex**, as well as **PortAudio::device_count** and
+    /// **PortAudio::host_api_count**, are unaffected.
+    ///
+    /// Should PortAudio's default host API not be among them, the first of the given host APIs
+    /// that is available becomes the default instead.
+    pub fn host_apis<I>(mut self, host_apis: I) -> Self
+    where
+        I: IntoIterator<Item = HostApiTypeId>,
+    {
+        self.host_apis = Some(host_apis.into_iter().collect());
+        self
+    }
+
+    /// Prefer the given host API over PortAudio's default host API, if it is available.
+    ///
+    /// This determines the result of **PortAudio::default_host_api** and so the devices returned
+    /// by **PortAudio::default_input_device** and **PortAudio::default_output_device**.
+    pub fn default_host_api(mut self, host_api: HostApiTypeId) -> Self {
+        self.default_host_api = Some(host_api);
+        self
+    }
+
+    /// Require the PortAudio library to be at least version `min`.
+    ///
+    /// Once PortAudio is initialised, **build** checks the version like
+    /// **PortAudio::require_version** and, if the running library is older, releases the instance
+    /// and returns an `ErrorKind::UnsupportedVersion` error.
+    pub fn require_version(mut self, min: VersionInfo) -> Self {
+        self.min_version = Some(min);
+        self
+    }
+
+    /// Call `hook` just before `Pa_Initialize`, e.g. to configure a host API extension that
+    /// PortAudio only reads during initialisation. Hooks are called in the order they are added,
+    /// and the first to return an **Error** aborts the **build**.
+    ///
+    /// The hook is called while rust-portaudio's lock on the PortAudio API is held, so it may call
+    /// the functions of `portaudio_sys` directly but must not call any method of rust-portaudio,
+    /// which would deadlock.
+    ///
+    /// As hooks can't take effect once PortAudio is initialised, **build** returns an
+    /// `ErrorKind::OtherInstancesAlive` error without calling them while any other **PortAudio**
+    /// instance is alive within the process. This differs from a builder without hooks, which
+    /// shares the existing initialisation just like **PortAudio::new**.
+    ///
+    /// The hooks are only called once. **PortAudio::rescan_devices** re-initialises PortAudio
+    /// without calling them again, so any configuration they made that PortAudio doesn't retain
+    /// across `Pa_Terminate` is lost by a rescan.
+    pub fn pre_init<F>(mut self, hook: F) -> Self
+    where
+        F: FnOnce() -> Result<(), Error> + 'static,
+    {
+        self.pre_init.push(Box::new(hook));
+        self
+    }
+
+    /// Initialise PortAudio with the given options.
+    pub fn build(self) -> Result<PortAudio, Error> {
+        let Builder {
+            host_apis,
+            default_host_api,
+            min_version,
+            pre_init,
+        } = self;
+        let mut port_audio = if pre_init.is_empty() {
+            PortAudio::new()?
+        } else {
+            PortAudio::initialized_with(move || pre_init.into_iter().try_for_each(|hook| hook()))?
+        };
+        if let Some(min) = min_version {
+            port_audio.require_version(min)?;
+        }
+        port_audio.host_apis = host_apis;
+        port_audio.default_host_api = default_host_api;
+        Ok(port_audio)
+    }
+}
+
+impl fmt::Debug for Builder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Builder")
+            .field("host_apis", &self.host_apis)
+            .field("default_host_api", &self.default_host_api)
+            .field("min_version", &self.min_version)
+            .field("pre_init", &self.pre_init.len())
+            .finish()
+    }
+}
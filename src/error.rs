@@ -4,156 +4,428 @@
 //!
 
 use ffi;
+use std::fmt;
+use types::{HostApiTypeId, VersionInfo};
 
-enum_from_primitive! {
-/// Error codes returned by PortAudio functions.
-#[repr(i32)]
+/// The kinds of error that may be returned by PortAudio functions.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
-pub enum Error {
-    /// No Error
-    NoError =
-        ffi::PaErrorCode_paNoError,
+pub enum ErrorKind {
     /// No audio devices
-    NoDevice =
-        ffi::PA_NO_DEVICE,
+    NoDevice,
     /// Portaudio not initialized
-    NotInitialized =
-        ffi::PaErrorCode_paNotInitialized,
+    NotInitialized,
     /// Unanticipated error from the host
-    UnanticipatedHostError =
-        ffi::PaErrorCode_paUnanticipatedHostError,
+    ///
+    /// The details reported by the host API are available via **Error::host_error**.
+    UnanticipatedHostError,
     /// Invalid channel count
-    InvalidChannelCount =
-        ffi::PaErrorCode_paInvalidChannelCount,
+    InvalidChannelCount,
     /// Invalid sample rate
-    InvalidSampleRate =
-        ffi::PaErrorCode_paInvalidSampleRate,
+    InvalidSampleRate,
     /// Invalid Device
-    InvalidDevice =
-        ffi::PaErrorCode_paInvalidDevice,
+    InvalidDevice,
     /// Invalid Flag
-    InvalidFlag =
-        ffi::PaErrorCode_paInvalidFlag,
+    InvalidFlag,
     /// The Sample format is not supported
-    SampleFormatNotSupported =
-        ffi::PaErrorCode_paSampleFormatNotSupported,
+    SampleFormatNotSupported,
     /// Input device not compatible with output device
-    BadIODeviceCombination =
-        ffi::PaErrorCode_paBadIODeviceCombination,
+    BadIODeviceCombination,
     /// Memory insufficient
-    InsufficientMemory =
-        ffi::PaErrorCode_paInsufficientMemory,
+    InsufficientMemory,
     /// The buffer is too big
-    BufferTooBig =
-        ffi::PaErrorCode_paBufferTooBig,
+    BufferTooBig,
     /// The buffer is too small
-    BufferTooSmall =
-        ffi::PaErrorCode_paBufferTooSmall,
+    BufferTooSmall,
     /// Invalid callback
-    NullCallback =
-        ffi::PaErrorCode_paNullCallback,
+    NullCallback,
     /// Invalid Stream
-    BadStreamPtr =
-        ffi::PaErrorCode_paBadStreamPtr,
+    BadStreamPtr,
     /// Time out
-    TimedOut =
-        ffi::PaErrorCode_paTimedOut,
+    TimedOut,
     /// Portaudio internal error
-    InternalError =
-        ffi::PaErrorCode_paInternalError,
+    InternalError,
     /// Device unavailable
-    DeviceUnavailable =
-        ffi::PaErrorCode_paDeviceUnavailable,
+    DeviceUnavailable,
     /// Stream info not compatible with the host
-    IncompatibleHostApiSpecificStreamInfo =
-        ffi::PaErrorCode_paIncompatibleHostApiSpecificStreamInfo,
+    IncompatibleHostApiSpecificStreamInfo,
     /// The stream is stopped
-    StreamIsStopped =
-        ffi::PaErrorCode_paStreamIsStopped,
+    StreamIsStopped,
     /// The stream is not stopped
-    StreamIsNotStopped =
-        ffi::PaErrorCode_paStreamIsNotStopped,
+    StreamIsNotStopped,
     /// The input stream has overflowed
-    InputOverflowed =
-        ffi::PaErrorCode_paInputOverflowed,
+    InputOverflowed,
     /// The output has underflowed
-    OutputUnderflowed =
-        ffi::PaErrorCode_paOutputUnderflowed,
+    OutputUnderflowed,
     /// The host API is not found by Portaudio
-    HostApiNotFound =
-        ffi::PaErrorCode_paHostApiNotFound,
+    HostApiNotFound,
     /// The host API is invalid
-    InvalidHostApi =
-        ffi::PaErrorCode_paInvalidHostApi,
+    InvalidHostApi,
     /// Portaudio cannot read from the callback stream
-    CanNotReadFromACallbackStream =
-        ffi::PaErrorCode_paCanNotReadFromACallbackStream,
+    CanNotReadFromACallbackStream,
     /// Portaudio cannot write to the callback stream
-    CanNotWriteToACallbackStream =
-        ffi::PaErrorCode_paCanNotWriteToACallbackStream,
+    CanNotWriteToACallbackStream,
     /// Portaudio cannot read from an output only stream
-    CanNotReadFromAnOutputOnlyStream =
-        ffi::PaErrorCode_paCanNotReadFromAnOutputOnlyStream,
+    CanNotReadFromAnOutputOnlyStream,
     /// Portaudio cannot write to an input only stream
-    CanNotWriteToAnInputOnlyStream =
-        ffi::PaErrorCode_paCanNotWriteToAnInputOnlyStream,
+    CanNotWriteToAnInputOnlyStream,
     /// The stream is not compatible with the host API
-    IncompatibleStreamHostApi =
-        ffi::PaErrorCode_paIncompatibleStreamHostApi,
+    IncompatibleStreamHostApi,
     /// Invalid buffer
-    BadBufferPtr =
-        ffi::PaErrorCode_paBadBufferPtr,
+    BadBufferPtr,
+    /// The operation requires that no **Stream**s are open, however some still are.
+    ///
+    /// This is not a PortAudio error code and is only ever produced by rust-portaudio itself.
+    StreamsStillOpen,
+    /// The operation requires that no other **PortAudio** instances are alive within the process,
+    /// however some still are.
+    ///
+    /// This is not a PortAudio error code and is only ever produced by rust-portaudio itself.
+    OtherInstancesAlive,
+    /// The running PortAudio build is older than required.
+    ///
+    /// This is not a PortAudio error code and is only ever produced by rust-portaudio itself.
+    ///
+    /// Versions are given as numbers in the format returned by `Pa_GetVersion` (see
+    /// **VersionInfo::from_version_number**).
+    UnsupportedVersion {
+        /// The minimum version that was required.
+        required: i32,
+        /// The version of the running PortAudio build.
+        found: i32,
+    },
+    /// The PortAudio library could not be loaded at runtime. Only returned with the
+    /// `runtime-load` feature.
+    LibraryNotFound,
+    /// The PortAudio library loaded at runtime lacks the named symbol. Only returned with the
+    /// `runtime-load` feature.
+    MissingSymbol(&'static str),
+    /// An error code unknown to this version of rust-portaudio, most likely added in a newer
+    /// version of PortAudio.
+    Unknown(i32),
 }
+
+/// An error returned by rust-portaudio.
+///
+/// Along with its **ErrorKind**, an **Error** describes the operation that failed and, in the
+/// case of an `ErrorKind::UnanticipatedHostError`, the details reported by the host API at the
+/// time of the failure.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Error {
+    kind: ErrorKind,
+    context: Option<&'static str>,
+    host_error: Option<HostError>,
+}
+
+/// An owned copy of the information PortAudio holds about the last error reported by a host API.
+///
+/// Captured alongside an `ErrorKind::UnanticipatedHostError`, as PortAudio's own copy is
+/// overwritten by the next host error.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HostError {
+    /// The host API which returned the error code
+    pub host_api: HostApiTypeId,
+    /// The host API specific error code
+    pub code: i64,
+    /// The host API's description of the error
+    pub text: String,
 }
 
-impl ::std::fmt::Display for Error {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
-        write!(f, "{:?}", self)
+impl From<ffi::PaError> for ErrorKind {
+    fn from(code: ffi::PaError) -> Self {
+        match code {
+            ffi::PA_NO_DEVICE => ErrorKind::NoDevice,
+            ffi::PaErrorCode_paNotInitialized => ErrorKind::NotInitialized,
+            ffi::PaErrorCode_paUnanticipatedHostError => ErrorKind::UnanticipatedHostError,
+            ffi::PaErrorCode_paInvalidChannelCount => ErrorKind::InvalidChannelCount,
+            ffi::PaErrorCode_paInvalidSampleRate => ErrorKind::InvalidSampleRate,
+            ffi::PaErrorCode_paInvalidDevice => ErrorKind::InvalidDevice,
+            ffi::PaErrorCode_paInvalidFlag => ErrorKind::InvalidFlag,
+            ffi::PaErrorCode_paSampleFormatNotSupported => ErrorKind::SampleFormatNotSupported,
+            ffi::PaErrorCode_paBadIODeviceCombination => ErrorKind::BadIODeviceCombination,
+            ffi::PaErrorCode_paInsufficientMemory => ErrorKind::InsufficientMemory,
+            ffi::PaErrorCode_paBufferTooBig => ErrorKind::BufferTooBig,
+            ffi::PaErrorCode_paBufferTooSmall => ErrorKind::BufferTooSmall,
+            ffi::PaErrorCode_paNullCallback => ErrorKind::NullCallback,
+            ffi::PaErrorCode_paBadStreamPtr => ErrorKind::BadStreamPtr,
+            ffi::PaErrorCode_paTimedOut => ErrorKind::TimedOut,
+            ffi::PaErrorCode_paInternalError => ErrorKind::InternalError,
+            ffi::PaErrorCode_paDeviceUnavailable => ErrorKind::DeviceUnavailable,
+            ffi::PaErrorCode_paIncompatibleHostApiSpecificStreamInfo => {
+                ErrorKind::IncompatibleHostApiSpecificStreamInfo
+            }
+            ffi::PaErrorCode_paStreamIsStopped => ErrorKind::StreamIsStopped,
+            ffi::PaErrorCode_paStreamIsNotStopped => ErrorKind::StreamIsNotStopped,
+            ffi::PaErrorCode_paInputOverflowed => ErrorKind::InputOverflowed,
+            ffi::PaErrorCode_paOutputUnderflowed => ErrorKind::OutputUnderflowed,
+            ffi::PaErrorCode_paHostApiNotFound => ErrorKind::HostApiNotFound,
+            ffi::PaErrorCode_paInvalidHostApi => ErrorKind::InvalidHostApi,
+            ffi::PaErrorCode_paCanNotReadFromACallbackStream => {
+                ErrorKind::CanNotReadFromACallbackStream
+            }
+            ffi::PaErrorCode_paCanNotWriteToACallbackStream => {
+                ErrorKind::CanNotWriteToACallbackStream
+            }
+            ffi::PaErrorCode_paCanNotReadFromAnOutputOnlyStream => {
+                ErrorKind::CanNotReadFromAnOutputOnlyStream
+            }
+            ffi::PaErrorCode_paCanNotWriteToAnInputOnlyStream => {
+                ErrorKind::CanNotWriteToAnInputOnlyStream
+            }
+            ffi::PaErrorCode_paIncompatibleStreamHostApi => ErrorKind::IncompatibleStreamHostApi,
+            ffi::PaErrorCode_paBadBufferPtr => ErrorKind::BadBufferPtr,
+            code => ErrorKind::Unknown(code),
+        }
     }
 }
 
-impl ::std::error::Error for Error {
-    fn description(&self) -> &str {
-        match *self {
-            Error::NoError => "No Error",
-            Error::NoDevice => "No Device",
-            Error::NotInitialized => "PortAudio not initialized",
-            Error::UnanticipatedHostError => "Unanticipated error from the host",
-            Error::InvalidChannelCount => "Invalid number of channels",
-            Error::InvalidSampleRate => "Invalid sample rate",
-            Error::InvalidDevice => "Invalid device",
-            Error::InvalidFlag => "Invalid flag",
-            Error::SampleFormatNotSupported => "Sample format is not supported",
-            Error::BadIODeviceCombination => "Input device not compatible with output device",
-            Error::InsufficientMemory => "Memory insufficient",
-            Error::BufferTooBig => "The buffer is too big",
-            Error::BufferTooSmall => "The buffer is too small",
-            Error::NullCallback => "Invalid callback",
-            Error::BadStreamPtr => "Invalid stream",
-            Error::TimedOut => "Time out",
-            Error::InternalError => "Portaudio internal error",
-            Error::DeviceUnavailable => "Device unavailable",
-            Error::IncompatibleHostApiSpecificStreamInfo => {
-                "Stream info not compatible with the host"
+impl ErrorKind {
+    /// The PortAudio error code corresponding to this kind of error.
+    ///
+    /// Returns `None` for kinds of error that are only produced by rust-portaudio itself.
+    pub fn code(&self) -> Option<ffi::PaError> {
+        let code = match *self {
+            ErrorKind::NoDevice => ffi::PA_NO_DEVICE,
+            ErrorKind::NotInitialized => ffi::PaErrorCode_paNotInitialized,
+            ErrorKind::UnanticipatedHostError => ffi::PaErrorCode_paUnanticipatedHostError,
+            ErrorKind::InvalidChannelCount => ffi::PaErrorCode_paInvalidChannelCount,
+            ErrorKind::InvalidSampleRate => ffi::PaErrorCode_paInvalidSampleRate,
+            ErrorKind::InvalidDevice => ffi::PaErrorCode_paInvalidDevice,
+            ErrorKind::InvalidFlag => ffi::PaErrorCode_paInvalidFlag,
+            ErrorKind::SampleFormatNotSupported => ffi::PaErrorCode_paSampleFormatNotSupported,
+            ErrorKind::BadIODeviceCombination => ffi::PaErrorCode_paBadIODeviceCombination,
+            ErrorKind::InsufficientMemory => ffi::PaErrorCode_paInsufficientMemory,
+            ErrorKind::BufferTooBig => ffi::PaErrorCode_paBufferTooBig,
+            ErrorKind::BufferTooSmall => ffi::PaErrorCode_paBufferTooSmall,
+            ErrorKind::NullCallback => ffi::PaErrorCode_paNullCallback,
+            ErrorKind::BadStreamPtr => ffi::PaErrorCode_paBadStreamPtr,
+            ErrorKind::TimedOut => ffi::PaErrorCode_paTimedOut,
+            ErrorKind::InternalError => ffi::PaErrorCode_paInternalError,
+            ErrorKind::DeviceUnavailable => ffi::PaErrorCode_paDeviceUnavailable,
+            ErrorKind::IncompatibleHostApiSpecificStreamInfo => {
+                ffi::PaErrorCode_paIncompatibleHostApiSpecificStreamInfo
             }
-            Error::StreamIsStopped => "The stream is stopped",
-            Error::StreamIsNotStopped => "The stream is not stopped",
-            Error::InputOverflowed => "The input stream has overflowed",
-            Error::OutputUnderflowed => "The output stream has underflowed",
-            Error::HostApiNotFound => "The host api is not found by Portaudio",
-            Error::InvalidHostApi => "The host API is invalid",
-            Error::CanNotReadFromACallbackStream => {
-                "Portaudio cannot read from the callback stream"
+            ErrorKind::StreamIsStopped => ffi::PaErrorCode_paStreamIsStopped,
+            ErrorKind::StreamIsNotStopped => ffi::PaErrorCode_paStreamIsNotStopped,
+            ErrorKind::InputOverflowed => ffi::PaErrorCode_paInputOverflowed,
+            ErrorKind::OutputUnderflowed => ffi::PaErrorCode_paOutputUnderflowed,
+            ErrorKind::HostApiNotFound => ffi::PaErrorCode_paHostApiNotFound,
+            ErrorKind::InvalidHostApi => ffi::PaErrorCode_paInvalidHostApi,
+            ErrorKind::CanNotReadFromACallbackStream => {
+                ffi::PaErrorCode_paCanNotReadFromACallbackStream
             }
-            Error::CanNotWriteToACallbackStream => "Portaudio cannot write to the callback stream",
-            Error::CanNotReadFromAnOutputOnlyStream => {
-                "Portaudio cannot read from an output only stream"
+            ErrorKind::CanNotWriteToACallbackStream => {
+                ffi::PaErrorCode_paCanNotWriteToACallbackStream
             }
-            Error::CanNotWriteToAnInputOnlyStream => {
-                "Portaudio cannot write to an input only stream"
+            ErrorKind::CanNotReadFromAnOutputOnlyStream => {
+                ffi::PaErrorCode_paCanNotReadFromAnOutputOnlyStream
             }
-            Error::IncompatibleStreamHostApi => "The stream is not compatible with the host API",
-            Error::BadBufferPtr => "Invalid buffer",
+            ErrorKind::CanNotWriteToAnInputOnlyStream => {
+                ffi::PaErrorCode_paCanNotWriteToAnInputOnlyStream
+            }
+            ErrorKind::IncompatibleStreamHostApi => ffi::PaErrorCode_paIncompatibleStreamHostApi,
+            ErrorKind::BadBufferPtr => ffi::PaErrorCode_paBadBufferPtr,
+            ErrorKind::StreamsStillOpen
+            | ErrorKind::OtherInstancesAlive
+            | ErrorKind::UnsupportedVersion { .. }
+            | ErrorKind::LibraryNotFound
+            | ErrorKind::MissingSymbol(_) => return None,
+            ErrorKind::Unknown(code) => code,
+        };
+        Some(code)
+    }
+
+    /// A human-readable description of this kind of error.
+    ///
+    /// The description of PortAudio's own error codes is provided by `Pa_GetErrorText`.
+    pub fn description(&self) -> &'static str {
+        match *self {
+            // `PA_NO_DEVICE` is a device index rather than an error code, so PortAudio has no
+            // text for it.
+            ErrorKind::NoDevice => "No device available",
+            ErrorKind::StreamsStillOpen => "One or more streams are still open",
+            ErrorKind::OtherInstancesAlive => "Other PortAudio instances are still alive",
+            ErrorKind::UnsupportedVersion { .. } => "The PortAudio version is not supported",
+            ErrorKind::LibraryNotFound => "The PortAudio library could not be loaded",
+            ErrorKind::MissingSymbol(_) => "The PortAudio library lacks a required symbol",
+            ErrorKind::Unknown(_) => "Unknown error code",
+            kind => {
+                let code = kind.code().expect("all PortAudio error kinds have a code");
+                unsafe { ffi::c_str_to_str(ffi::Pa_GetErrorText(code)) }
+                    .unwrap_or("<Failed to convert str from CStr>")
+            }
+        }
+    }
+}
+
+impl Error {
+    /// Construct an error of the given kind without any context.
+    pub fn new(kind: ErrorKind) -> Self {
+        Error {
+            kind,
+            context: None,
+            host_error: None,
+        }
+    }
+
+    /// Construct an error of the given kind that occurred during the given operation.
+    pub fn with_context(kind: ErrorKind, context: &'static str) -> Self {
+        Error {
+            kind,
+            context: Some(context),
+            host_error: None,
+        }
+    }
+
+    /// Construct an error from a code returned by the PortAudio function named by `context`.
+    ///
+    /// If the code indicates an `ErrorKind::UnanticipatedHostError`, the details of the error are
+    /// read from `Pa_GetLastHostErrorInfo`. This should therefore be called immediately after the
+    /// failing function has returned.
+    pub fn from_code(code: ffi::PaError, context: &'static str) -> Self {
+        let kind = ErrorKind::from(code);
+        let host_error = match kind {
+            ErrorKind::UnanticipatedHostError => last_host_error(),
+            _ => None,
+        };
+        Error {
+            kind,
+            context: Some(context),
+            host_error,
+        }
+    }
+
+    /// The kind of the error.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The operation during which the error occurred, if known.
+    ///
+    /// For errors returned by PortAudio this is the name of the failing PortAudio function.
+    pub fn context(&self) -> Option<&'static str> {
+        self.context
+    }
+
+    /// The details reported by the host API for an `ErrorKind::UnanticipatedHostError`.
+    pub fn host_error(&self) -> Option<&HostError> {
+        self.host_error.as_ref()
+    }
+}
+
+/// Copy PortAudio's information about the last host API error, if there is any.
+fn last_host_error() -> Option<HostError> {
+    let c_info = unsafe { ffi::Pa_GetLastHostErrorInfo() };
+    if c_info.is_null() {
+        return None;
+    }
+    let c_info = unsafe { *c_info };
+    let text = ffi::c_str_to_str(c_info.errorText).unwrap_or("<Failed to convert str from CStr>");
+    Some(HostError {
+        host_api: c_info.hostApiType.into(),
+        code: c_info.errorCode as i64,
+        text: text.to_string(),
+    })
+}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Self {
+        Error::new(kind)
+    }
+}
+
+impl PartialEq<ErrorKind> for Error {
+    fn eq(&self, kind: &ErrorKind) -> bool {
+        self.kind == *kind
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ErrorKind::Unknown(code) => write!(f, "Unknown error code {}", code),
+            ErrorKind::MissingSymbol(symbol) => {
+                write!(f, "The PortAudio library lacks the `{}` symbol", symbol)
+            }
+            ErrorKind::UnsupportedVersion { required, found } => write!(
+                f,
+                "PortAudio version {} is required but version {} is running",
+                VersionInfo::from_version_number(required, ""),
+                VersionInfo::from_version_number(found, "")
+            ),
+            ref kind => write!(f, "{}", kind.description()),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(context) = self.context {
+            write!(f, "{}: ", context)?;
+        }
+        write!(f, "{}", self.kind)?;
+        if let Some(ref host_error) = self.host_error {
+            write!(
+                f,
+                " ({:?} error {}: {})",
+                host_error.host_api, host_error.code, host_error.text
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl ::std::error::Error for Error {
+    fn description(&self) -> &str {
+        self.kind.description()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, ErrorKind};
+    use ffi;
+
+    #[test]
+    fn known_error_codes_round_trip() {
+        let codes = ffi::PaErrorCode_paNotInitialized..=ffi::PaErrorCode_paBadBufferPtr;
+        for code in codes.chain(Some(ffi::PA_NO_DEVICE)) {
+            let kind = ErrorKind::from(code);
+            assert!(kind != ErrorKind::Unknown(code), "{}", code);
+            assert_eq!(kind.code(), Some(code));
+        }
+    }
+
+    #[test]
+    fn unknown_error_codes_are_kept() {
+        // `paCanNotInitializeRecursively` was added after PortAudio 19.7.0.
+        let codes = [ffi::PaErrorCode_paBadBufferPtr + 1, -10001, -42, 1];
+        for &code in &codes {
+            let kind = ErrorKind::from(code);
+            assert_eq!(kind, ErrorKind::Unknown(code));
+            assert_eq!(kind.code(), Some(code));
+            assert_eq!(kind.to_string(), format!("Unknown error code {}", code));
+        }
+    }
+
+    #[test]
+    fn unknown_error_code_from_call() {
+        let error = Error::from_code(-42, "Pa_OpenStream");
+        assert_eq!(error.kind(), ErrorKind::Unknown(-42));
+        assert_eq!(error.context(), Some("Pa_OpenStream"));
+        assert!(error.host_error().is_none());
+        assert_eq!(error.to_string(), "Pa_OpenStream: Unknown error code -42");
+    }
+
+    #[test]
+    fn errors_of_rust_portaudio_have_no_code() {
+        let kinds = [
+            ErrorKind::StreamsStillOpen,
+            ErrorKind::OtherInstancesAlive,
+            ErrorKind::LibraryNotFound,
+            ErrorKind::MissingSymbol("Pa_GetVersionInfo"),
+        ];
+        for kind in &kinds {
+            assert_eq!(kind.code(), None);
         }
     }
 }
@@ -0,0 +1,205 @@
+//! The ALSA specific API, available when the linked PortAudio includes the ALSA host API.
+//!
+//! The methods of [**AlsaStreamExt**](./trait.AlsaStreamExt.html) apply to a **Stream** opened
+//! on an ALSA device, while [**set_num_periods**](./fn.set_num_periods.html) and
+//! [**set_retries_busy**](./fn.set_retries_busy.html) configure every ALSA stream opened after
+//! they are called. [**AlsaStreamInfo**](./struct.AlsaStreamInfo.html) opens an ALSA PCM by
+//! name.
+//!
+//! With the `runtime-load` feature, the functions of this module return an
+//! `ErrorKind::MissingSymbol` error if the loaded library lacks the ALSA host API.
+//!
+//! ```no_run
+//! extern crate portaudio as pa;
+//! use pa::ext::alsa::{self, AlsaStreamExt};
+//!
+//! # fn main() {
+//! let pa = pa::PortAudio::new().unwrap();
+//! alsa::set_num_periods(2).unwrap();
+//! let settings = pa.default_output_stream_settings::<f32>(2, 44_100.0, 256).unwrap();
+//! let mut stream = pa.open_blocking_stream(settings).unwrap();
+//! stream.enable_realtime_scheduling(true).unwrap();
+//! println!("Playing on card {}", stream.output_card().unwrap());
+//! # }
+//! ```
+
+use std::ffi::{CStr, CString, NulError};
+use std::fmt;
+use std::mem;
+use std::os::raw::{c_int, c_ulong, c_void};
+
+use error::Error;
+use ffi;
+use stream::{HostApiSpecificStreamInfo, Stream};
+use types::HostApiTypeId;
+
+/// ALSA specific stream information, used to open an ALSA PCM by name.
+///
+/// This allows opening PCMs that PortAudio does not enumerate, such as `plughw:1,0`, `dmix` or a
+/// device defined in `asound.conf`. Attach it to **Parameters** constructed with
+/// **Parameters::host_api_specific_device**.
+///
+/// ```no_run
+/// extern crate portaudio as pa;
+///
+/// # fn main() {
+/// let pa = pa::PortAudio::new().unwrap();
+/// let info = pa::ext::alsa::AlsaStreamInfo::new("plughw:1,0").unwrap();
+/// let params = pa::StreamParameters::<f32>::host_api_specific_device(2, true, 0.1)
+///     .with_host_api_specific_stream_info(info);
+/// let settings = pa::OutputStreamSettings::new(params, 44_100.0, 256);
+/// let mut stream = pa.open_blocking_stream(settings).unwrap();
+/// # }
+/// ```
+pub struct AlsaStreamInfo {
+    device_string: CString,
+    // Boxed so that the address passed to PortAudio is unaffected by moving the **Parameters**.
+    info: Box<ffi::PaAlsaStreamInfo>,
+}
+
+/// ALSA specific methods of a **Stream**.
+///
+/// Each returns an **IncompatibleStreamHostApi** error if the stream was not opened on an ALSA
+/// device.
+pub trait AlsaStreamExt {
+    /// Whether to give the stream's audio thread the `SCHED_FIFO` real-time scheduling policy
+    /// when it is started.
+    ///
+    /// This must be called before **Stream::start** to take effect.
+    fn enable_realtime_scheduling(&mut self, enable: bool) -> Result<(), Error>;
+
+    /// The ALSA card index of the stream's input device.
+    ///
+    /// Returns a **DeviceUnavailable** error if the stream has no input.
+    fn input_card(&self) -> Result<i32, Error>;
+
+    /// The ALSA card index of the stream's output device.
+    ///
+    /// Returns a **DeviceUnavailable** error if the stream has no output.
+    fn output_card(&self) -> Result<i32, Error>;
+}
+
+impl<M, F> AlsaStreamExt for Stream<M, F> {
+    fn enable_realtime_scheduling(&mut self, enable: bool) -> Result<(), Error> {
+        ::require_symbol("PaAlsa_EnableRealtimeScheduling")?;
+        ::require_symbol("PaAlsa_GetStreamOutputCard")?;
+        let _guard = ::lock();
+        let pa_stream = self.unsafe_pa_stream();
+        // PortAudio doesn't check the host API of the stream passed to
+        // `PaAlsa_EnableRealtimeScheduling`, so check it here by querying either card.
+        let mut card: c_int = 0;
+        let error_code = unsafe { ffi::PaAlsa_GetStreamOutputCard(pa_stream, &mut card) };
+        if error_code == ffi::PaErrorCode_paIncompatibleStreamHostApi
+            || error_code == ffi::PaErrorCode_paBadStreamPtr
+        {
+            return Err(Error::from_code(
+                error_code,
+                "PaAlsa_EnableRealtimeScheduling",
+            ));
+        }
+        unsafe { ffi::PaAlsa_EnableRealtimeScheduling(pa_stream, enable as c_int) };
+        Ok(())
+    }
+
+    fn input_card(&self) -> Result<i32, Error> {
+        ::require_symbol("PaAlsa_GetStreamInputCard")?;
+        let _guard = ::lock();
+        let mut card: c_int = 0;
+        let error_code =
+            unsafe { ffi::PaAlsa_GetStreamInputCard(self.unsafe_pa_stream(), &mut card) };
+        ::result_from_error_code(error_code, "PaAlsa_GetStreamInputCard").map(|()| card)
+    }
+
+    fn output_card(&self) -> Result<i32, Error> {
+        ::require_symbol("PaAlsa_GetStreamOutputCard")?;
+        let _guard = ::lock();
+        let mut card: c_int = 0;
+        let error_code =
+            unsafe { ffi::PaAlsa_GetStreamOutputCard(self.unsafe_pa_stream(), &mut card) };
+        ::result_from_error_code(error_code, "PaAlsa_GetStreamOutputCard").map(|()| card)
+    }
+}
+
+/// Set the number of periods (buffer fragments) that ALSA devices are configured with by streams
+/// opened after this call.
+///
+/// PortAudio uses 4 periods by default and requires at least 2. Fewer periods may reduce latency
+/// at the risk of underruns.
+pub fn set_num_periods(num_periods: i32) -> Result<(), Error> {
+    ::require_symbol("PaAlsa_SetNumPeriods")?;
+    let _guard = ::lock();
+    let error_code = unsafe { ffi::PaAlsa_SetNumPeriods(num_periods) };
+    ::result_from_error_code(error_code, "PaAlsa_SetNumPeriods")
+}
+
+/// Set the maximum number of times that opening a busy ALSA device is retried, with a short sleep
+/// between each attempt, by streams opened after this call.
+pub fn set_retries_busy(retries: i32) -> Result<(), Error> {
+    ::require_symbol("PaAlsa_SetRetriesBusy")?;
+    let _guard = ::lock();
+    let error_code = unsafe { ffi::PaAlsa_SetRetriesBusy(retries) };
+    ::result_from_error_code(error_code, "PaAlsa_SetRetriesBusy")
+}
+
+impl AlsaStreamInfo {
+    /// Stream information that opens the ALSA PCM with the given name.
+    ///
+    /// Returns an error if the `device_string` contains a NUL byte.
+    pub fn new<T: Into<Vec<u8>>>(device_string: T) -> Result<Self, NulError> {
+        CString::new(device_string).map(AlsaStreamInfo::from_c_string)
+    }
+
+    fn from_c_string(device_string: CString) -> Self {
+        // Initialised as by `PaAlsa_InitializeStreamInfo`, which is not called so that no library
+        // lacking it is required.
+        let info = ffi::PaAlsaStreamInfo {
+            size: mem::size_of::<ffi::PaAlsaStreamInfo>() as c_ulong,
+            hostApiType: ffi::PaHostApiTypeId_paALSA,
+            version: 1,
+            deviceString: device_string.as_ptr(),
+        };
+        AlsaStreamInfo {
+            device_string,
+            info: Box::new(info),
+        }
+    }
+
+    /// The name of the ALSA PCM to open.
+    pub fn device_string(&self) -> &CStr {
+        &self.device_string
+    }
+}
+
+unsafe impl HostApiSpecificStreamInfo for AlsaStreamInfo {
+    fn host_api_type(&self) -> HostApiTypeId {
+        HostApiTypeId::ALSA
+    }
+
+    fn as_ptr(&self) -> *const c_void {
+        &*self.info as *const ffi::PaAlsaStreamInfo as *const c_void
+    }
+}
+
+// The `deviceString` pointer only refers to the immutable string owned by the **AlsaStreamInfo**.
+unsafe impl Send for AlsaStreamInfo {}
+unsafe impl Sync for AlsaStreamInfo {}
+
+impl Clone for AlsaStreamInfo {
+    fn clone(&self) -> Self {
+        AlsaStreamInfo::from_c_string(self.device_string.clone())
+    }
+}
+
+impl PartialEq for AlsaStreamInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.device_string == other.device_string
+    }
+}
+
+impl fmt::Debug for AlsaStreamInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AlsaStreamInfo")
+            .field("device_string", &self.device_string)
+            .finish()
+    }
+}
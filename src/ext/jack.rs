@@ -0,0 +1,96 @@
+//! The JACK specific API, available when the linked PortAudio includes the JACK host API.
+//!
+//! PortAudio connects to the JACK server during initialisation, so the name of the JACK client
+//! under which every stream appears must be chosen before then.
+//! [**JackBuilderExt**](./trait.JackBuilderExt.html) sets it through a **PortAudioBuilder**, and
+//! [**JackPortAudioExt**](./trait.JackPortAudioExt.html) reports the name that the JACK server
+//! actually assigned.
+//!
+//! With the `runtime-load` feature, these return an `ErrorKind::MissingSymbol` error if the loaded
+//! library lacks the JACK host API.
+//!
+//! ```no_run
+//! extern crate portaudio as pa;
+//! use pa::ext::jack::{JackBuilderExt, JackPortAudioExt};
+//!
+//! # fn main() {
+//! let pa = pa::PortAudio::builder()
+//!     .jack_client_name("synth")
+//!     .unwrap()
+//!     .build()
+//!     .unwrap();
+//! println!("Connected to JACK as {}", pa.jack_client_name().unwrap());
+//! # }
+//! ```
+
+use std::ffi::{CStr, CString, NulError};
+use std::os::raw::c_char;
+use std::ptr;
+use std::sync::Mutex;
+
+use builder::Builder;
+use error::Error;
+use ffi;
+use PortAudio;
+
+/// The client name most recently passed to `PaJack_SetClientName`.
+///
+/// PortAudio keeps the pointer rather than copying the string and reads it again whenever it is
+/// re-initialised, so the string must live until it is replaced by another.
+static CLIENT_NAME: Mutex<Option<CString>> = Mutex::new(None);
+
+/// JACK specific options of a **PortAudioBuilder**, which PortAudio only reads during
+/// initialisation.
+///
+/// Each is set by a **PortAudioBuilder::pre_init** hook, so **build** returns an
+/// `ErrorKind::OtherInstancesAlive` error while any other **PortAudio** instance is alive within
+/// the process, even though the same builder without JACK options would share that instance's
+/// initialisation.
+pub trait JackBuilderExt: Sized {
+    /// Request the given JACK client name instead of the default `PortAudio`.
+    ///
+    /// Returns an error if the `client_name` contains a NUL byte. **build** returns an
+    /// `ErrorKind::InvalidFlag` error if it is longer than JACK allows.
+    fn jack_client_name<T: Into<Vec<u8>>>(self, client_name: T) -> Result<Self, NulError>;
+}
+
+/// JACK specific methods of **PortAudio**.
+pub trait JackPortAudioExt {
+    /// The name of the JACK client under which PortAudio's streams appear.
+    ///
+    /// This may differ from the name requested through **JackBuilderExt::jack_client_name**, as
+    /// the JACK server appends a suffix when another client already has that name.
+    fn jack_client_name(&self) -> Result<String, Error>;
+}
+
+impl JackBuilderExt for Builder {
+    fn jack_client_name<T: Into<Vec<u8>>>(self, client_name: T) -> Result<Self, NulError> {
+        let client_name = CString::new(client_name)?;
+        Ok(self.pre_init(move || set_client_name(client_name)))
+    }
+}
+
+/// Pass the `client_name` to `PaJack_SetClientName`, keeping it alive for as long as PortAudio
+/// may read it. Must only be called just before `Pa_Initialize`.
+fn set_client_name(client_name: CString) -> Result<(), Error> {
+    ::require_symbol("PaJack_SetClientName")?;
+    let mut stored = CLIENT_NAME.lock().unwrap_or_else(|err| err.into_inner());
+    let error_code = unsafe { ffi::PaJack_SetClientName(client_name.as_ptr()) };
+    ::result_from_error_code(error_code, "PaJack_SetClientName")?;
+    // Moving the `CString` doesn't move the string that PortAudio now points to.
+    *stored = Some(client_name);
+    Ok(())
+}
+
+impl JackPortAudioExt for PortAudio {
+    fn jack_client_name(&self) -> Result<String, Error> {
+        ::require_symbol("PaJack_GetClientName")?;
+        let _guard = ::lock();
+        let mut client_name: *const c_char = ptr::null();
+        let error_code = unsafe { ffi::PaJack_GetClientName(&mut client_name) };
+        ::result_from_error_code(error_code, "PaJack_GetClientName")?;
+        // Despite the header's claim, the string belongs to the JACK client and must not be freed.
+        let client_name = unsafe { CStr::from_ptr(client_name) };
+        Ok(client_name.to_string_lossy().into_owned())
+    }
+}
@@ -21,6 +21,11 @@
 
 //! Portaudio platform specific extensions.
 
+#[cfg(portaudio_alsa)]
+pub mod alsa;
+#[cfg(portaudio_jack)]
+pub mod jack;
+
 //#[cfg(any(target_os="macos"))]
 //pub mod mac_core;
 
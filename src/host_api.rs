@@ -0,0 +1,121 @@
+//! A handle to one of the host APIs available to a **PortAudio** instance.
+//!
+//! The primary type of interest is [**HostApi**](./struct.HostApi.html).
+
+use super::error::{Error, ErrorKind};
+use super::types::{DeviceIndex, DeviceInfo, HostApiIndex, HostApiInfo, HostApiTypeId};
+use super::PortAudio;
+
+/// A handle to a host API, produced by the
+/// [**PortAudio::host_apis**](../struct.PortAudio.html#method.host_apis) and
+/// [**PortAudio::host_api**](../struct.PortAudio.html#method.host_api) methods.
+///
+/// A **HostApi** may only live as long as the **PortAudio** instance from which it was produced.
+#[derive(Clone, Debug)]
+pub struct HostApi<'a> {
+    index: HostApiIndex,
+    info: HostApiInfo<'a>,
+    port_audio: &'a PortAudio,
+}
+
+/// An iterator yielding the **DeviceIndex** of each device belonging to a **HostApi** along with
+/// their respective **DeviceInfo**s.
+#[derive(Clone, Debug)]
+pub struct Devices<'a> {
+    host_api: HostApiIndex,
+    total: u32,
+    next: u32,
+    port_audio: &'a PortAudio,
+}
+
+impl<'a> HostApi<'a> {
+    /// Construct a handle to the host API at the given index.
+    ///
+    /// Returns an **Error** of kind `ErrorKind::InvalidHostApi` if the index is out of range or
+    /// PortAudio's information about the host API can't be read.
+    pub fn from_index(port_audio: &'a PortAudio, index: HostApiIndex) -> Result<Self, Error> {
+        match port_audio.host_api_info(index) {
+            Some(info) => Ok(HostApi {
+                index,
+                info,
+                port_audio,
+            }),
+            None => Err(Error::with_context(
+                ErrorKind::InvalidHostApi,
+                "Pa_GetHostApiInfo",
+            )),
+        }
+    }
+
+    /// The runtime index of the host API.
+    ///
+    /// This index is only valid until PortAudio is re-initialised.
+    pub fn index(&self) -> HostApiIndex {
+        self.index
+    }
+
+    /// PortAudio's information about the host API.
+    pub fn info(&self) -> &HostApiInfo<'a> {
+        &self.info
+    }
+
+    /// The unchanging unique identifier of the host API.
+    pub fn type_id(&self) -> HostApiTypeId {
+        self.info.host_type
+    }
+
+    /// The name of the host API.
+    pub fn name(&self) -> &'a str {
+        self.info.name
+    }
+
+    /// The number of devices belonging to the host API.
+    pub fn device_count(&self) -> u32 {
+        self.info.device_count
+    }
+
+    /// Produces an iterator yielding the **DeviceIndex** of each device belonging to the host API
+    /// along with their respective **DeviceInfo**s.
+    pub fn devices(&self) -> Devices<'a> {
+        Devices {
+            host_api: self.index,
+            total: self.info.device_count,
+            next: 0,
+            port_audio: self.port_audio,
+        }
+    }
+
+    /// The index of the host API's default input device.
+    ///
+    /// Returns an **Error** of kind `ErrorKind::NoDevice` if the host API has no input devices.
+    pub fn default_input(&self) -> Result<DeviceIndex, Error> {
+        self.info
+            .default_input_device
+            .ok_or_else(|| Error::with_context(ErrorKind::NoDevice, "HostApi::default_input"))
+    }
+
+    /// The index of the host API's default output device.
+    ///
+    /// Returns an **Error** of kind `ErrorKind::NoDevice` if the host API has no output devices.
+    pub fn default_output(&self) -> Result<DeviceIndex, Error> {
+        self.info
+            .default_output_device
+            .ok_or_else(|| Error::with_context(ErrorKind::NoDevice, "HostApi::default_output"))
+    }
+}
+
+impl<'a> Iterator for Devices<'a> {
+    type Item = Result<(DeviceIndex, DeviceInfo<'a>), Error>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next < self.total {
+            let host_api_device_index = self.next as i32;
+            self.next += 1;
+            let result = self
+                .port_audio
+                .api_device_index_to_device_index(self.host_api, host_api_device_index)
+                .and_then(|idx| self.port_audio.device_info(idx).map(|info| (idx, info)));
+            return Some(result);
+        }
+        None
+    }
+}
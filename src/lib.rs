@@ -57,36 +57,46 @@ extern crate libc;
 extern crate num;
 extern crate portaudio_sys as ffi;
 
-use num::FromPrimitive;
 use std::option::Option;
 use std::os::raw;
 
-pub use error::Error;
+pub use builder::Builder as PortAudioBuilder;
+pub use error::{Error, ErrorKind};
 pub use ffi::{
     PaStreamCallbackResult as StreamCallbackResult, PA_ABORT as Abort, PA_COMPLETE as Complete,
     PA_CONTINUE as Continue,
 };
+pub use host_api::HostApi;
+pub use rescan::{DeviceChanges, DeviceSnapshot};
+pub use selector::DeviceSelector;
+pub use spec::DeviceSpec;
 pub use stream::{
     callback_flags as stream_callback_flags, flags as stream_flags, Available as StreamAvailable,
-    Blocking, CallbackFlags as StreamCallbackFlags, CallbackTimeInfo as StreamCallbackTimeInfo,
-    Duplex, DuplexCallbackArgs as DuplexStreamCallbackArgs, DuplexSettings as DuplexStreamSettings,
-    Flags as StreamFlags, Flow, Info as StreamInfo, Input,
-    InputCallbackArgs as InputStreamCallbackArgs, InputSettings as InputStreamSettings,
+    Blocking, CallbackFlags as StreamCallbackFlags, CallbackThreadInit,
+    CallbackTimeInfo as StreamCallbackTimeInfo, Duplex,
+    DuplexCallbackArgs as DuplexStreamCallbackArgs, DuplexSettings as DuplexStreamSettings,
+    Flags as StreamFlags, Flow, HostApiSpecificStreamInfo, Info as StreamInfo, Input,
+    InputCallbackArgs as InputStreamCallbackArgs, InputSettings as InputStreamSettings, Limiter,
     NonBlocking, Output, OutputCallbackArgs as OutputStreamCallbackArgs,
-    OutputSettings as OutputStreamSettings, Parameters as StreamParameters,
-    Settings as StreamSettings, Stream,
+    OutputOptions as OutputStreamOptions, OutputSettings as OutputStreamSettings,
+    Parameters as StreamParameters, SafetyOptions, SafetyStats, Settings as StreamSettings, Stream,
 };
 pub use types::{
-    DeviceIndex, DeviceInfo, Frames, HostApiIndex, HostApiInfo, HostApiTypeId, HostErrorInfo,
-    SampleFormat, Time, FRAMES_PER_BUFFER_UNSPECIFIED,
+    CDeviceInfo, CHostApiInfo, CHostErrorInfo, DeviceIndex, DeviceInfo, Direction, Frames,
+    HostApiIndex, HostApiInfo, HostApiTypeId, HostErrorInfo, SampleFormat, Time, VersionInfo,
+    FRAMES_PER_BUFFER_UNSPECIFIED,
 };
 
 use std::ptr;
 
-#[macro_use]
-mod enum_primitive;
+pub mod builder;
 pub mod error;
 pub mod ext;
+pub mod host_api;
+pub mod rescan;
+pub mod rt;
+pub mod selector;
+pub mod spec;
 pub mod stream;
 mod types;
 
@@ -94,12 +104,38 @@ mod types;
 ///
 /// We use a type here instead of pure functions in order to ensure correct intialisation and
 /// termination of the underlying PortAudio instance.
+///
+/// # Thread safety
+///
+/// PortAudio does not document its API as thread-safe, so rust-portaudio serialises every call
+/// into PortAudio's non-real-time API behind a single process-wide lock. This makes it safe to
+/// call any method of **PortAudio**, such as device enumeration or opening streams, and the
+/// control methods of **Stream** (`start`, `stop`, `abort`, `close`, `is_active`, `is_stopped`
+/// and `info`) concurrently from any number of threads. Note that a call may have to wait for
+/// another thread's call to complete. In particular, **Stream::stop** deliberately holds the lock
+/// while waiting for pending buffers to play, which may take as long as the stream's output
+/// latency, as PortAudio gives no guarantee that other calls are safe meanwhile. Use
+/// **Stream::abort** instead where other threads can't afford to wait. Dropping an active
+/// **Stream** stops it in the same way, so abort it first if the wait is undesirable.
+///
+/// The methods intended for use within audio loops do not acquire the lock and never wait on
+/// other threads: **Stream::time**, **Stream::cpu_load** and the blocking **Stream::read**,
+/// **Stream::write**, **Stream::read_available** and **Stream::write_available** methods. Each
+/// **Stream** must still only be read from or written to by one thread at a time.
+///
+/// Methods that acquire the lock must not be called from within a non-blocking stream's
+/// callback, as a concurrent **Stream::stop** holds the lock while waiting for the callback to
+/// return.
 #[derive(Debug)]
 pub struct PortAudio {
     /// The lifetime of the `PortAudio` API.
     ///
     /// The lifetime is shared between `PortAudio` and all its spawned `Stream`s.
     life: std::sync::Arc<Life>,
+    /// The host APIs considered by this instance, or `None` to consider all of them.
+    host_apis: Option<Vec<HostApiTypeId>>,
+    /// The host API preferred over PortAudio's default host API, if available.
+    default_host_api: Option<HostApiTypeId>,
 }
 
 /// The lifetime of the `PortAudio` instance.
@@ -112,6 +148,9 @@ pub struct Life {
     is_terminated: std::sync::Mutex<bool>,
 }
 
+/// The **Stream** returned by **PortAudio::open_blocking_stream**.
+type BlockingStream<F> = Stream<Blocking<<F as Flow>::Buffer>, F>;
+
 impl PortAudio {
     /// Construct a **PortAudio** instance.
     ///
@@ -123,19 +162,49 @@ impl PortAudio {
     /// `Drop`ped.
     ///
     /// It is safe to simultaneously construct more than one **PortAudio** instance, however this
-    /// is rarely necessary.
+    /// is rarely necessary. Initialisation is reference-counted across the whole process:
+    /// `Pa_Initialize` is only called for the first live instance and `Pa_Terminate` only once the
+    /// last instance and all of its **Stream**s have been dropped or terminated. Instances may be
+    /// dropped in any order.
+    ///
+    /// With the `runtime-load` feature, the PortAudio library is loaded by the first call,
+    /// returning an `ErrorKind::LibraryNotFound` or `ErrorKind::MissingSymbol` error on failure.
+    /// Functions that don't require a **PortAudio** instance, such as **version**, panic if the
+    /// library can't be loaded, so construct one first.
+    ///
+    /// See **PortAudio::builder** for further options.
     pub fn new() -> Result<Self, Error> {
-        unsafe {
-            let error = FromPrimitive::from_i32(ffi::Pa_Initialize()).unwrap();
-            match error {
-                Error::NoError => {
-                    let life = std::sync::Arc::new(Life {
-                        is_terminated: std::sync::Mutex::new(false),
-                    });
-                    Ok(PortAudio { life: life })
-                }
-                err => Err(err),
-            }
+        initialize()?;
+        Ok(PortAudio::from_initialization())
+    }
+
+    /// Produce a [**PortAudioBuilder**](./builder/struct.Builder.html) for constructing a
+    /// **PortAudio** instance that only considers certain host APIs, prefers another default host
+    /// API or configures host API extensions before initialisation.
+    pub fn builder() -> PortAudioBuilder {
+        PortAudioBuilder::new()
+    }
+
+    /// Construct a **PortAudio** instance, calling `pre_init` just before `Pa_Initialize`.
+    ///
+    /// Used by builders that configure PortAudio before initialisation (see `initialize_with`).
+    fn initialized_with<F>(pre_init: F) -> Result<Self, Error>
+    where
+        F: FnOnce() -> Result<(), Error>,
+    {
+        initialize_with(pre_init)?;
+        Ok(PortAudio::from_initialization())
+    }
+
+    /// A **PortAudio** instance holding an initialisation that has just been counted.
+    fn from_initialization() -> Self {
+        let life = std::sync::Arc::new(Life {
+            is_terminated: std::sync::Mutex::new(false),
+        });
+        PortAudio {
+            life,
+            host_apis: None,
+            default_host_api: None,
         }
     }
 
@@ -146,11 +215,78 @@ impl PortAudio {
     /// **Calling this method is optional**. It is only necessary if you require handling any
     /// PortAudio termination errors. Otherwise, `Pa_Terminate` will be called and all necessary
     /// cleanup will occur automatically when this **PortAudio** instance is **Drop**ped.
+    ///
+    /// If any **Stream** spawned from this instance is still alive, this returns an **Error** of
+    /// kind `ErrorKind::StreamsStillOpen` and termination is deferred until the last of those
+    /// **Stream**s is dropped. `Pa_Terminate` is also deferred while other **PortAudio** instances
+    /// are alive within the process (see **PortAudio::new**).
     pub fn terminate(self) -> Result<(), Error> {
+        if std::sync::Arc::strong_count(&self.life) > 1 {
+            return Err(Error::with_context(
+                ErrorKind::StreamsStillOpen,
+                "PortAudio::terminate",
+            ));
+        }
         *self.life.is_terminated.lock().unwrap() = true;
         terminate()
     }
 
+    /// Re-initialise PortAudio in order to refresh its list of available devices.
+    ///
+    /// PortAudio only enumerates devices during initialisation, so devices that are plugged in or
+    /// removed while the application is running are not otherwise noticed. This method terminates
+    /// and re-initialises the library and returns the devices that were added, removed or changed
+    /// in the process. Devices are matched between the two scans by their host API and name (see
+    /// **DeviceSnapshot**). Note that the **DeviceIndex** of unchanged devices may differ after a
+    /// rescan.
+    ///
+    /// Re-initialising invalidates every open stream, so this returns an **Error** of kind
+    /// `ErrorKind::StreamsStillOpen` without doing anything while any **Stream** spawned from
+    /// this instance is still alive. Taking `&mut self` ensures no new streams may be opened and no
+    /// borrowed **DeviceInfo**s or **HostApiInfo**s outlive the rescan.
+    ///
+    /// Other **PortAudio** instances in the process share this instance's initialisation of the
+    /// library (see **PortAudio::new**), so this returns an **Error** of kind
+    /// `ErrorKind::OtherInstancesAlive` without doing anything while any other instance is alive.
+    ///
+    /// The **PortAudioBuilder::pre_init** hooks of a built instance are not called again before
+    /// re-initialising, so only the configuration that PortAudio retains across `Pa_Terminate`,
+    /// such as the JACK client name, survives a rescan.
+    ///
+    /// If termination fails, this instance remains initialised as before. If re-initialisation
+    /// fails, this instance is left terminated and any subsequent calls to its methods will return
+    /// an **Error** of kind `ErrorKind::NotInitialized`.
+    pub fn rescan_devices(&mut self) -> Result<DeviceChanges, Error> {
+        if std::sync::Arc::strong_count(&self.life) > 1 {
+            return Err(Error::with_context(
+                ErrorKind::StreamsStillOpen,
+                "PortAudio::rescan_devices",
+            ));
+        }
+        let mut initializations = INITIALIZATIONS.lock().unwrap();
+        if *initializations > 1 {
+            return Err(Error::with_context(
+                ErrorKind::OtherInstancesAlive,
+                "PortAudio::rescan_devices",
+            ));
+        }
+        let before = rescan::snapshot(self)?;
+        {
+            let _guard = lock();
+            // Should termination fail, the library remains initialised and this instance with it.
+            result_from_error_code(unsafe { ffi::Pa_Terminate() }, "Pa_Terminate")?;
+            let error_code = unsafe { ffi::Pa_Initialize() };
+            if let Err(err) = result_from_error_code(error_code, "Pa_Initialize") {
+                *initializations -= 1;
+                *self.life.is_terminated.lock().unwrap() = true;
+                return Err(err);
+            }
+        }
+        drop(initializations);
+        let after = rescan::snapshot(self)?;
+        Ok(DeviceChanges::between(&before, &after))
+    }
+
     /// Retrieve the release number of the currently running PortAudio build.
     pub fn version(&self) -> i32 {
         version()
@@ -163,9 +299,52 @@ impl PortAudio {
         version_text()
     }
 
+    /// Retrieve structured information about the version of the currently running PortAudio
+    /// build.
+    pub fn version_info(&self) -> VersionInfo {
+        version_info()
+    }
+
+    /// Check that the currently running PortAudio build is at least version `min`.
+    ///
+    /// Useful immediately after initialisation for applications that depend upon behaviour only
+    /// present in newer versions of PortAudio, as the library that is loaded at runtime may be
+    /// older than the one the application was built against.
+    ///
+    /// Returns an **Error** of kind `ErrorKind::UnsupportedVersion` if the running build is older
+    /// than `min`.
+    ///
+    /// **Builder::require_version** performs the same check as part of initialisation.
+    ///
+    /// The methods of rust-portaudio don't check the version themselves. With the `runtime-load`
+    /// feature, those calling functions missing from the loaded library instead return an
+    /// `ErrorKind::MissingSymbol` error, while behaviour that differs between versions of the
+    /// same function is up to the application to guard with this method.
+    ///
+    /// ```no_run
+    /// extern crate portaudio as pa;
+    /// # fn main() {
+    /// let pa = pa::PortAudio::new().unwrap();
+    /// pa.require_version(pa::VersionInfo::new(19, 6, 0)).unwrap();
+    /// # }
+    /// ```
+    pub fn require_version(&self, min: VersionInfo) -> Result<(), Error> {
+        let found = self.version_info();
+        if found < min {
+            let kind = ErrorKind::UnsupportedVersion {
+                required: min.version_number(),
+                found: found.version_number(),
+            };
+            return Err(Error::with_context(kind, "PortAudio::require_version"));
+        }
+        Ok(())
+    }
+
     /// Produces an iterator yielding the **DeviceIndex** for each device along with their
     /// respective **DeviceInfo**s.
-    pub fn devices(&self) -> Result<Devices, Error> {
+    ///
+    /// Devices of host APIs excluded by **PortAudioBuilder::host_apis** are skipped.
+    pub fn devices(&self) -> Result<Devices<'_>, Error> {
         Ok(Devices {
             total: self.device_count()?,
             next: 0,
@@ -183,13 +362,14 @@ impl PortAudio {
     /// caused by PortAudio not being initialised, however this should not be possible with our
     /// type-safe **PortAudio** API.
     pub fn device_count(&self) -> Result<u32, Error> {
+        let _guard = lock();
         match unsafe { ffi::Pa_GetDeviceCount() } {
             n if n >= 0 => Ok(n as u32),
             // NOTE: The docs for this error (NO_DEVICE) specify that this simply indicates that
             // there are no devices available or that no available devices should be used. Thus, we
             // will simply translate this to a count of `0`.
             -1 => Ok(0),
-            err => Err(::num::FromPrimitive::from_i32(err).unwrap()),
+            err => Err(Error::from_code(err, "Pa_GetDeviceCount")),
         }
     }
 
@@ -200,11 +380,19 @@ impl PortAudio {
     ///
     /// Returns `Error` if no default input device is available or an error was encountered.
     ///
+    /// If this instance was built with host API options (see **PortAudioBuilder**), this is the
+    /// default input device of **PortAudio::default_host_api** instead.
+    ///
     /// **TODO:** Investigate exactly what errors may occur as the PA docs aren't clear on this.
     pub fn default_input_device(&self) -> Result<DeviceIndex, Error> {
+        if self.has_host_api_options() {
+            let context = "PortAudio::default_input_device";
+            return self.default_host_api_device(|info| info.default_input_device, context);
+        }
+        let _guard = lock();
         match unsafe { ffi::Pa_GetDefaultInputDevice() } {
             idx if idx >= 0 => Ok(DeviceIndex(idx as u32)),
-            err => Err(::num::FromPrimitive::from_i32(err).unwrap()),
+            err => Err(Error::from_code(err, "Pa_GetDefaultInputDevice")),
         }
     }
 
@@ -215,11 +403,19 @@ impl PortAudio {
     ///
     /// Returns `Error` if no default input device is available or an error was encountered.
     ///
+    /// If this instance was built with host API options (see **PortAudioBuilder**), this is the
+    /// default output device of **PortAudio::default_host_api** instead.
+    ///
     /// **TODO:** Investigate exactly what errors may occur as the PA docs aren't clear on this.
     pub fn default_output_device(&self) -> Result<DeviceIndex, Error> {
+        if self.has_host_api_options() {
+            let context = "PortAudio::default_output_device";
+            return self.default_host_api_device(|info| info.default_output_device, context);
+        }
+        let _guard = lock();
         match unsafe { ffi::Pa_GetDefaultOutputDevice() } {
             idx if idx >= 0 => Ok(DeviceIndex(idx as u32)),
-            err => Err(::num::FromPrimitive::from_i32(err).unwrap()),
+            err => Err(Error::from_code(err, "Pa_GetDefaultOutputDevice")),
         }
     }
 
@@ -227,28 +423,36 @@ impl PortAudio {
     ///
     /// Returns `Ok(DeviceInfo)` if successful.
     ///
-    /// Returns `Err(Error::InvalidDevice)` if the device parameter is out of range.
+    /// Returns an **Error** of kind `ErrorKind::InvalidDevice` if the device parameter is out of
+    /// range.
     ///
     /// # Arguments
     ///
     /// - device - A valid device index in the range 0 to (port_audio.device_count()-1).
-    pub fn device_info(&self, device: DeviceIndex) -> Result<DeviceInfo, Error> {
+    pub fn device_info(&self, device: DeviceIndex) -> Result<DeviceInfo<'_>, Error> {
+        let _guard = lock();
         let c_info = unsafe { ffi::Pa_GetDeviceInfo(device.into()) };
         if c_info.is_null() {
-            Err(Error::InvalidDevice)
+            Err(Error::with_context(
+                ErrorKind::InvalidDevice,
+                "Pa_GetDeviceInfo",
+            ))
         } else {
             Ok(DeviceInfo::from_c_info(unsafe { *c_info }))
         }
     }
 
-    /// Produces an iterator yielding the **HostApiIndex** of each available API along with their
-    /// respective **HostApiInfo**s.
-    pub fn host_apis(&self) -> HostApis {
-        HostApis {
-            total: self.host_api_count().unwrap_or(0),
+    /// Produces an iterator yielding a [**HostApi**](./host_api/struct.HostApi.html) handle for
+    /// each available host API.
+    ///
+    /// Host APIs excluded by **PortAudioBuilder::host_apis** are skipped. An item is an **Error** if
+    /// PortAudio fails to describe the host API at that index.
+    pub fn host_apis(&self) -> Result<HostApis<'_>, Error> {
+        Ok(HostApis {
+            total: self.host_api_count()?,
             next: 0,
             port_audio: self,
-        }
+        })
     }
 
     /// Retrieve the number of available host APIs.
@@ -260,7 +464,8 @@ impl PortAudio {
     ///
     /// TODO: Determine exactly what errors might occur (PA docs aren't clear on this).
     pub fn host_api_count(&self) -> Result<HostApiIndex, Error> {
-        unsafe { result_from_host_api_index(ffi::Pa_GetHostApiCount()) }
+        let _guard = lock();
+        unsafe { result_from_host_api_index(ffi::Pa_GetHostApiCount(), "Pa_GetHostApiCount") }
     }
 
     /// Retrieve the index of the default host API.
@@ -271,9 +476,37 @@ impl PortAudio {
     /// Return a non-negative value ranging from 0 to (get_host_api_count()-1) indicating the
     /// default host API index or an `Error` if an error is encountered.
     ///
+    /// If this instance was built with host API options (see **PortAudioBuilder**), the preferred
+    /// host API is returned if available. Otherwise, should PortAudio's default host API be
+    /// excluded, the first available of the considered host APIs is returned, or an **Error** of
+    /// kind `ErrorKind::HostApiNotFound` if there is none.
+    ///
     /// TODO: Determine exactly what errors might occur (PA docs aren't clear on this).
     pub fn default_host_api(&self) -> Result<HostApiIndex, Error> {
-        unsafe { result_from_host_api_index(ffi::Pa_GetDefaultHostApi()) }
+        if let Some(type_id) = self.default_host_api {
+            if let Ok(index) = self.considered_host_api_index(type_id) {
+                return Ok(index);
+            }
+        }
+        let index = {
+            let _guard = lock();
+            let index = unsafe { ffi::Pa_GetDefaultHostApi() };
+            result_from_host_api_index(index, "Pa_GetDefaultHostApi")?
+        };
+        let host_apis = match self.host_apis {
+            Some(ref host_apis) => host_apis,
+            None => return Ok(index),
+        };
+        if let Some(info) = self.host_api_info(index) {
+            if host_apis.contains(&info.host_type) {
+                return Ok(index);
+            }
+        }
+        host_apis
+            .iter()
+            .filter_map(|&type_id| self.host_api_type_id_to_host_api_index(type_id).ok())
+            .next()
+            .ok_or_else(|| Error::with_context(ErrorKind::HostApiNotFound, "Pa_GetDefaultHostApi"))
     }
 
     /// Retrieve a pointer to a structure containing information about a specific host Api.
@@ -286,6 +519,7 @@ impl PortAudio {
     ///
     /// Returns `None` if the `host_api` parameter is out of range or an error is encountered.
     pub fn host_api_info<'a>(&'a self, host_api: HostApiIndex) -> Option<HostApiInfo<'a>> {
+        let _guard = lock();
         let c_host_info = unsafe { ffi::Pa_GetHostApiInfo(host_api as HostApiIndex) };
         if c_host_info.is_null() {
             None
@@ -294,6 +528,67 @@ impl PortAudio {
         }
     }
 
+    /// Produce a [**HostApi**](./host_api/struct.HostApi.html) handle for the host API with the
+    /// given unique identifier.
+    ///
+    /// Returns an **Error** of kind `ErrorKind::HostApiNotFound` if the host API is not available
+    /// or is excluded by **PortAudioBuilder::host_apis**.
+    pub fn host_api(&self, type_id: HostApiTypeId) -> Result<HostApi<'_>, Error> {
+        let index = self.considered_host_api_index(type_id)?;
+        HostApi::from_index(self, index)
+    }
+
+    /// Whether this instance considers the host API with the given unique identifier.
+    fn considers_host_api(&self, type_id: HostApiTypeId) -> bool {
+        match self.host_apis {
+            Some(ref host_apis) => host_apis.contains(&type_id),
+            None => true,
+        }
+    }
+
+    /// Whether this instance considers the host API of the given device.
+    fn considers_device(&self, info: &DeviceInfo) -> bool {
+        if self.host_apis.is_none() {
+            return true;
+        }
+        match self.host_api_info(info.host_api) {
+            Some(host_api_info) => self.considers_host_api(host_api_info.host_type),
+            None => false,
+        }
+    }
+
+    /// Whether this instance was built with any options affecting its choice of host API.
+    fn has_host_api_options(&self) -> bool {
+        self.host_apis.is_some() || self.default_host_api.is_some()
+    }
+
+    /// The runtime index of the given host API, if it is available and considered.
+    fn considered_host_api_index(&self, type_id: HostApiTypeId) -> Result<HostApiIndex, Error> {
+        if !self.considers_host_api(type_id) {
+            return Err(Error::with_context(
+                ErrorKind::HostApiNotFound,
+                "Pa_HostApiTypeIdToHostApiIndex",
+            ));
+        }
+        self.host_api_type_id_to_host_api_index(type_id)
+    }
+
+    /// The device chosen by `select` from the information about **PortAudio::default_host_api**.
+    fn default_host_api_device<F>(
+        &self,
+        select: F,
+        context: &'static str,
+    ) -> Result<DeviceIndex, Error>
+    where
+        F: FnOnce(&HostApiInfo) -> Option<DeviceIndex>,
+    {
+        let index = self.default_host_api()?;
+        let info = self
+            .host_api_info(index)
+            .ok_or_else(|| Error::with_context(ErrorKind::InvalidHostApi, context))?;
+        select(&info).ok_or_else(|| Error::with_context(ErrorKind::NoDevice, context))
+    }
+
     /// Convert a static host API unique identifier, into a runtime host API index.
     ///
     /// # Arguments
@@ -308,8 +603,9 @@ impl PortAudio {
         &self,
         type_id: HostApiTypeId,
     ) -> Result<HostApiIndex, Error> {
-        let id = FromPrimitive::from_i32(type_id as i32).unwrap();
-        unsafe { result_from_host_api_index(ffi::Pa_HostApiTypeIdToHostApiIndex(id)) }
+        let _guard = lock();
+        let idx = unsafe { ffi::Pa_HostApiTypeIdToHostApiIndex(type_id.into()) };
+        result_from_host_api_index(idx, "Pa_HostApiTypeIdToHostApiIndex")
     }
 
     /// Convert a host-API-specific device index to standard PortAudio device index.
@@ -321,7 +617,7 @@ impl PortAudio {
     ///
     /// - `host_api` - A valid host API index ranging from 0 to (get_host_api_count()-1)
     /// - `host_api_device_index` - A valid per-host device index in the range 0 to
-    /// (get_host_api_info(host_api).device_count-1)
+    ///   (get_host_api_info(host_api).device_count-1)
     ///
     /// Return a non-negative `DeviceIndex` ranging from 0 to (get_device_count()-1)
     /// or an `Error` if an error is encountered.
@@ -332,11 +628,12 @@ impl PortAudio {
         host_api: HostApiIndex,
         host_api_device_index: i32,
     ) -> Result<DeviceIndex, Error> {
+        let _guard = lock();
         let result =
             unsafe { ffi::Pa_HostApiDeviceIndexToDeviceIndex(host_api, host_api_device_index) };
         match result {
             idx if idx >= 0 => Ok(DeviceIndex(idx as u32)),
-            err => Err(::num::FromPrimitive::from_i32(err).unwrap()),
+            err => Err(Error::from_code(err, "Pa_HostApiDeviceIndexToDeviceIndex")),
         }
     }
 
@@ -355,7 +652,8 @@ impl PortAudio {
     where
         I: Sample,
     {
-        is_format_supported(Some(params.into()), None, sample_rate)
+        params.check_host_api_specific_stream_info("Pa_IsFormatSupported")?;
+        is_format_supported(Some((&params).into()), None, sample_rate)
     }
 
     /// Determine whether it would be possible to open an output-only stream with the specified
@@ -373,7 +671,8 @@ impl PortAudio {
     where
         O: Sample,
     {
-        is_format_supported(None, Some(params.into()), sample_rate)
+        params.check_host_api_specific_stream_info("Pa_IsFormatSupported")?;
+        is_format_supported(None, Some((&params).into()), sample_rate)
     }
 
     /// Determine whether it would be possible to open a duplex stream with the specified
@@ -393,7 +692,13 @@ impl PortAudio {
         I: Sample,
         O: Sample,
     {
-        is_format_supported(Some(in_params.into()), Some(out_params.into()), sample_rate)
+        in_params.check_host_api_specific_stream_info("Pa_IsFormatSupported")?;
+        out_params.check_host_api_specific_stream_info("Pa_IsFormatSupported")?;
+        is_format_supported(
+            Some((&in_params).into()),
+            Some((&out_params).into()),
+            sample_rate,
+        )
     }
 
     /// Open a new blocking [**Stream**](./stream/struct.Stream.html) with the given settings.
@@ -407,15 +712,12 @@ impl PortAudio {
     /// respectively.
     ///
     /// The returned **Stream** is inactive (stopped).
-    pub fn open_blocking_stream<S>(
-        &self,
-        settings: S,
-    ) -> Result<Stream<Blocking<<S::Flow as Flow>::Buffer>, S::Flow>, Error>
+    pub fn open_blocking_stream<S>(&self, settings: S) -> Result<BlockingStream<S::Flow>, Error>
     where
         S: StreamSettings,
         S::Flow: Flow,
     {
-        Stream::<Blocking<<S::Flow as Flow>::Buffer>, S::Flow>::open(self.life.clone(), settings)
+        BlockingStream::<S::Flow>::open(self.life.clone(), settings)
     }
 
     /// Open a new non-blocking [**Stream**](./stream/struct.Stream.html) with the given settings.
@@ -451,6 +753,44 @@ impl PortAudio {
     /// stop the **Stream**. **Output** stream `callback`s must always fill the entire buffer
     /// irrespective of its return value.
     ///
+    /// The buffers within the `callback`'s arguments are only valid for a single invocation, after
+    /// which PortAudio reuses their memory. The arguments' lifetime is therefore limited to the
+    /// call, so the buffers can't be stashed elsewhere:
+    ///
+    /// ```compile_fail
+    /// extern crate portaudio as pa;
+    /// # fn main() {
+    /// let pa = pa::PortAudio::new().unwrap();
+    /// let settings = pa.default_output_stream_settings::<f32>(2, 44_100.0, 256).unwrap();
+    /// let (sender, _receiver) = ::std::sync::mpsc::channel();
+    /// let callback = move |args: pa::OutputStreamCallbackArgs<f32>| {
+    ///     sender.send(args.buffer).unwrap();
+    ///     pa::Continue
+    /// };
+    /// let _stream = pa.open_non_blocking_stream(settings, callback);
+    /// # }
+    /// ```
+    ///
+    /// As the `callback` must accept arguments of any lifetime, a closure that is not passed
+    /// directly to this method requires its argument's type to be annotated, e.g.
+    /// `|args: pa::OutputStreamCallbackArgs<f32>|`, in order for Rust to infer as much.
+    ///
+    /// The `callback` is called from PortAudio's audio thread and so must be `Send`:
+    ///
+    /// ```compile_fail
+    /// extern crate portaudio as pa;
+    /// # fn main() {
+    /// let pa = pa::PortAudio::new().unwrap();
+    /// let settings = pa.default_output_stream_settings::<f32>(2, 44_100.0, 256).unwrap();
+    /// let calls = ::std::rc::Rc::new(::std::cell::Cell::new(0));
+    /// let callback = move |_args: pa::OutputStreamCallbackArgs<f32>| {
+    ///     calls.set(calls.get() + 1);
+    ///     pa::Continue
+    /// };
+    /// let _stream = pa.open_non_blocking_stream(settings, callback);
+    /// # }
+    /// ```
+    ///
     /// The returned **Stream** is inactive (stopped).
     pub fn open_non_blocking_stream<S, C>(
         &self,
@@ -460,11 +800,33 @@ impl PortAudio {
     where
         S: StreamSettings,
         S::Flow: Flow,
-        C: FnMut(<S::Flow as Flow>::CallbackArgs) -> ffi::PaStreamCallbackResult + 'static,
+        C: for<'a> FnMut(<S::Flow as Flow>::CallbackArgs<'a>) -> ffi::PaStreamCallbackResult
+            + Send
+            + 'static,
     {
         Stream::<NonBlocking, S::Flow>::open(self.life.clone(), settings, callback)
     }
 
+    /// Open a non-blocking **Stream** as with **PortAudio::open_non_blocking_stream**, running
+    /// `init` on PortAudio's callback thread before the first call to the `callback`.
+    ///
+    /// See **CallbackThreadInit** for the available presets.
+    pub fn open_non_blocking_stream_with_init<S, C>(
+        &self,
+        settings: S,
+        init: CallbackThreadInit,
+        callback: C,
+    ) -> Result<Stream<NonBlocking, S::Flow>, Error>
+    where
+        S: StreamSettings,
+        S::Flow: Flow,
+        C: for<'a> FnMut(<S::Flow as Flow>::CallbackArgs<'a>) -> ffi::PaStreamCallbackResult
+            + Send
+            + 'static,
+    {
+        Stream::<NonBlocking, S::Flow>::open_with_init(self.life.clone(), settings, init, callback)
+    }
+
     /// Produce the default **StreamParameters** for an **Input** **Stream**.
     ///
     /// The device used will be the default input device for the default Host API.
@@ -576,7 +938,7 @@ impl PortAudio {
     ///
     /// The function may sleep longer than requested so don't rely on this for accurate musical
     /// timing.
-    pub fn sleep(&self, m_sec: i32) -> () {
+    pub fn sleep(&self, m_sec: i32) {
         unsafe { ffi::Pa_Sleep(m_sec as raw::c_long) }
     }
 
@@ -593,6 +955,7 @@ impl PortAudio {
     /// The values in this structure will only be valid if a PortAudio function or method has
     /// previously returned the UnanticipatedHostError error code.
     pub fn last_host_error_info<'a>(&'a self) -> HostErrorInfo<'a> {
+        let _guard = lock();
         let c_error = unsafe { ffi::Pa_GetLastHostErrorInfo() };
         HostErrorInfo::from_c_error_info(unsafe { *c_error })
     }
@@ -616,6 +979,159 @@ pub fn version_text() -> Result<&'static str, ::std::str::Utf8Error> {
     unsafe { ffi::c_str_to_str(ffi::Pa_GetVersionText()) }
 }
 
+/// Retrieve structured information about the version of the current PortAudio build.
+///
+/// `Pa_GetVersionInfo` is only available as of PortAudio 19.5.0, so for older builds the
+/// information is instead derived from `Pa_GetVersion` and `Pa_GetVersionText`, and the
+/// `control_revision` is left empty. The same applies when the crate was built against the header
+/// of an older PortAudio, unless the library is loaded at runtime.
+pub fn version_info() -> VersionInfo {
+    let number = version();
+    let from_version_number =
+        || VersionInfo::from_version_number(number, version_text().unwrap_or(""));
+    // A library loaded at runtime may lack the symbol regardless of the version it reports.
+    if VersionInfo::from_version_number(number, "") < VersionInfo::new(19, 5, 0)
+        || require_symbol("Pa_GetVersionInfo").is_err()
+    {
+        return from_version_number();
+    }
+    c_version_info().unwrap_or_else(from_version_number)
+}
+
+/// The version information returned by `Pa_GetVersionInfo`, or `None` if it returns null.
+#[cfg(any(feature = "runtime-load", portaudio_v19_5))]
+fn c_version_info() -> Option<VersionInfo> {
+    let c_info = unsafe { ffi::Pa_GetVersionInfo() };
+    if c_info.is_null() {
+        None
+    } else {
+        Some(VersionInfo::from_c_info(unsafe { *c_info }))
+    }
+}
+
+/// The header the crate was built against predates `Pa_GetVersionInfo`, so linking to it could
+/// fail.
+#[cfg(not(any(feature = "runtime-load", portaudio_v19_5)))]
+fn c_version_info() -> Option<VersionInfo> {
+    None
+}
+
+/// The number of live **PortAudio** initialisations within the process.
+///
+/// Each **Life** holds one initialisation until it is dropped or terminated. PortAudio is only
+/// initialised when the count rises from zero and only terminated when it returns to zero.
+static INITIALIZATIONS: std::sync::Mutex<usize> = std::sync::Mutex::new(0);
+
+/// Serialises calls into PortAudio's non-real-time API, which is not documented as thread-safe.
+///
+/// Never held by the real-time methods used within audio loops (see the **PortAudio** docs). The
+/// only user code called while it is held are the **Builder::pre_init** hooks, which must not call
+/// back into rust-portaudio as they run just before `Pa_Initialize` under the same lock. Must only
+/// ever be acquired after, never before, `INITIALIZATIONS`.
+static FFI_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Acquire the `FFI_LOCK`.
+///
+/// The lock guards no data, so a poisoned lock is simply recovered.
+fn lock() -> std::sync::MutexGuard<'static, ()> {
+    FFI_LOCK.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+/// This is used by the **PortAudio::new** method.
+///
+/// Library initialization function - call this before using PortAudio.
+///
+/// This function initializes internal data structures and prepares underlying host APIs for use.
+/// `Pa_Initialize` is only called if PortAudio is not already initialised by another instance.
+///
+/// Return NoError if successful, otherwise an error code indicating the cause of failure.
+fn initialize() -> Result<(), Error> {
+    let mut initializations = INITIALIZATIONS.lock().unwrap();
+    if *initializations == 0 {
+        initialize_library(|| Ok(()))?;
+    }
+    *initializations += 1;
+    Ok(())
+}
+
+/// This is used by the **PortAudio::initialized_with** method.
+///
+/// Like `initialize`, but calls `pre_init` with the `FFI_LOCK` held just before `Pa_Initialize`,
+/// e.g. to set options that PortAudio only reads during initialisation.
+///
+/// As such options would be silently ignored while PortAudio is already initialised by another
+/// instance, this returns an `ErrorKind::OtherInstancesAlive` error without calling `pre_init`
+/// in that case.
+fn initialize_with<F>(pre_init: F) -> Result<(), Error>
+where
+    F: FnOnce() -> Result<(), Error>,
+{
+    let mut initializations = INITIALIZATIONS.lock().unwrap();
+    if *initializations > 0 {
+        return Err(Error::with_context(
+            ErrorKind::OtherInstancesAlive,
+            "Pa_Initialize",
+        ));
+    }
+    initialize_library(pre_init)?;
+    *initializations += 1;
+    Ok(())
+}
+
+/// Load the library if necessary and call `Pa_Initialize`, preceded by `pre_init`.
+///
+/// Must only be called while holding `INITIALIZATIONS` with a count of zero.
+fn initialize_library<F>(pre_init: F) -> Result<(), Error>
+where
+    F: FnOnce() -> Result<(), Error>,
+{
+    load_library()?;
+    let _guard = lock();
+    pre_init()?;
+    let error_code = unsafe { ffi::Pa_Initialize() };
+    result_from_error_code(error_code, "Pa_Initialize")
+}
+
+/// Load the PortAudio library at runtime, if not already loaded.
+#[cfg(feature = "runtime-load")]
+fn load_library() -> Result<(), Error> {
+    ffi::load().map_err(error_from_load_error)
+}
+
+/// The library is linked at build time, so there is nothing to load.
+#[cfg(not(feature = "runtime-load"))]
+fn load_library() -> Result<(), Error> {
+    Ok(())
+}
+
+/// Check that the PortAudio library has the named function, loading it at runtime if not already
+/// loaded.
+///
+/// Must be called before any function that a library may lack, such as those of the host API
+/// extensions, whose wrappers panic if it is missing.
+#[cfg(feature = "runtime-load")]
+fn require_symbol(symbol: &'static str) -> Result<(), Error> {
+    ffi::require(symbol).map_err(error_from_load_error)
+}
+
+/// The library is linked at build time, so every function is present.
+#[cfg(not(feature = "runtime-load"))]
+fn require_symbol(_symbol: &'static str) -> Result<(), Error> {
+    Ok(())
+}
+
+#[cfg(feature = "runtime-load")]
+fn error_from_load_error(err: ffi::LoadError) -> Error {
+    match err {
+        ffi::LoadError::LibraryNotFound(_) => {
+            Error::with_context(ErrorKind::LibraryNotFound, "dlopen")
+        }
+        ffi::LoadError::MissingSymbol(symbol) => {
+            Error::with_context(ErrorKind::MissingSymbol(symbol), "dlsym")
+        }
+    }
+}
+
 /// This is used by the **PortAudio::terminate** method.
 ///
 /// Library termination function - call this when finished using PortAudio.
@@ -627,7 +1143,7 @@ pub fn version_text() -> Result<&'static str, ::std::str::Utf8Error> {
 /// corresponding call to terminate().
 ///
 /// The final matching call to terminate() will automatically close any PortAudio streams that are
-/// still open.
+/// still open. `Pa_Terminate` is only called by the final matching call.
 ///
 /// terminate() MUST be called before exiting a program which uses PortAudio. Failure to do so may
 /// result in serious resource leaks, such as audio devices not being available until the next
@@ -635,13 +1151,14 @@ pub fn version_text() -> Result<&'static str, ::std::str::Utf8Error> {
 ///
 /// Return NoError if successful, otherwise an error code indicating the cause of failure.
 fn terminate() -> Result<(), Error> {
-    unsafe {
-        let error = FromPrimitive::from_i32(ffi::Pa_Terminate()).unwrap();
-        match error {
-            Error::NoError => Ok(()),
-            err => Err(err),
-        }
+    let mut initializations = INITIALIZATIONS.lock().unwrap();
+    *initializations -= 1;
+    if *initializations == 0 {
+        let _guard = lock();
+        let error_code = unsafe { ffi::Pa_Terminate() };
+        result_from_error_code(error_code, "Pa_Terminate")?;
     }
+    Ok(())
 }
 
 /// This is used by the **PortAudio::is_*_format_supported** methods.
@@ -650,13 +1167,13 @@ fn terminate() -> Result<(), Error> {
 ///
 /// # Arguments
 /// * input_parameters - A structure that describes the input parameters used to open a stream.
-/// The suggestedLatency field is ignored. See StreamParameters for a description of these
-/// parameters. inputParameters must be None for output-only streams.
+///   The suggestedLatency field is ignored. See StreamParameters for a description of these
+///   parameters. inputParameters must be None for output-only streams.
 /// * output_parameters - A structure that describes the output parameters used to open a stream.
-/// The suggestedLatency field is ignored. See StreamParameters for a description of these
-/// parameters. outputParameters must be None for input-only streams.
-/// * sample_rate - The required sampleRate. For full-duplex streams it is the sample rate for both
-/// input and output.
+///   The suggestedLatency field is ignored. See StreamParameters for a description of these
+///   parameters. outputParameters must be None for input-only streams.
+/// * sample_rate - The required sampleRate. For full-duplex streams it is the sample rate for
+///   both input and output.
 ///
 /// Return Ok(()) if the format is supported, and an Error indicating why the format is not
 /// supported otherwise. The constant PaFormatIsSupported is provided to compare with the return
@@ -673,20 +1190,20 @@ fn is_format_supported(
         .as_ref()
         .map(|output| output as *const _);
     if c_input.is_none() && c_output.is_none() {
-        Err(Error::InvalidDevice)
+        Err(Error::with_context(
+            ErrorKind::InvalidDevice,
+            "Pa_IsFormatSupported",
+        ))
     } else {
-        unsafe {
-            let error_code = ffi::Pa_IsFormatSupported(
+        let _guard = lock();
+        let error_code = unsafe {
+            ffi::Pa_IsFormatSupported(
                 c_input.unwrap_or(ptr::null()),
                 c_output.unwrap_or(ptr::null()),
                 sample_rate as raw::c_double,
-            );
-            let error = FromPrimitive::from_i32(error_code).unwrap();
-            match error {
-                Error::NoError => Ok(()),
-                err => Err(err),
-            }
-        }
+            )
+        };
+        result_from_error_code(error_code, "Pa_IsFormatSupported")
     }
 }
 
@@ -698,8 +1215,7 @@ pub struct Devices<'a> {
     port_audio: &'a PortAudio,
 }
 
-/// An iterator yielding the **HostApiIndex** for each available API along with their respective
-/// **HostApiInfo**s.
+/// An iterator yielding a **HostApi** handle for each available host API.
 #[derive(Clone, Debug)]
 pub struct HostApis<'a> {
     total: HostApiIndex,
@@ -710,33 +1226,51 @@ pub struct HostApis<'a> {
 impl<'a> Iterator for Devices<'a> {
     type Item = Result<(DeviceIndex, DeviceInfo<'a>), Error>;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.next < self.total {
+        while self.next < self.total {
             let idx = DeviceIndex(self.next);
             self.next += 1;
-            return Some(self.port_audio.device_info(idx).map(|info| (idx, info)));
+            match self.port_audio.device_info(idx) {
+                Ok(ref info) if !self.port_audio.considers_device(info) => continue,
+                result => return Some(result.map(|info| (idx, info))),
+            }
         }
         None
     }
 }
 
 impl<'a> Iterator for HostApis<'a> {
-    type Item = (HostApiIndex, HostApiInfo<'a>);
+    type Item = Result<HostApi<'a>, Error>;
     fn next(&mut self) -> Option<Self::Item> {
         while self.next < self.total {
             let idx = self.next;
             self.next += 1;
-            if let Some(info) = self.port_audio.host_api_info(idx) {
-                return Some((idx, info));
+            match HostApi::from_index(self.port_audio, idx) {
+                Ok(ref host_api) if !self.port_audio.considers_host_api(host_api.type_id()) => {
+                    continue
+                }
+                result => return Some(result),
             }
         }
         None
     }
 }
 
-fn result_from_host_api_index(idx: ffi::PaHostApiIndex) -> Result<HostApiIndex, Error> {
+/// Convert the result of the PortAudio function named by `context` into an **Error** if it is
+/// negative.
+fn result_from_error_code(code: ffi::PaError, context: &'static str) -> Result<(), Error> {
+    match code {
+        code if code >= 0 => Ok(()),
+        err => Err(Error::from_code(err, context)),
+    }
+}
+
+fn result_from_host_api_index(
+    idx: ffi::PaHostApiIndex,
+    context: &'static str,
+) -> Result<HostApiIndex, Error> {
     match idx {
         idx if idx >= 0 => Ok(idx),
-        err => Err(::num::FromPrimitive::from_i32(err).unwrap()),
+        err => Err(Error::from_code(err, context)),
     }
 }
 
@@ -747,15 +1281,17 @@ fn result_from_host_api_index(idx: ffi::PaHostApiIndex) -> Result<HostApiIndex,
 pub fn get_sample_size(format: SampleFormat) -> Result<u8, Error> {
     let result = unsafe { ffi::Pa_GetSampleSize(format as ffi::PaSampleFormat) };
     if result < 0 {
-        Err(::num::FromPrimitive::from_i32(result).unwrap())
+        Err(Error::from_code(result, "Pa_GetSampleSize"))
     } else {
         Ok(result as u8)
     }
 }
 
 mod private {
+    use super::stream::HostApiSpecificStreamInfo;
     use super::types::SampleFormat;
     use num::{FromPrimitive, ToPrimitive};
+    use std::any::Any;
     use std::ops::{Add, Div, Mul, Sub};
 
     /// internal private trait for Sample format management
@@ -777,6 +1313,42 @@ mod private {
         }
         /// get the sample format
         fn to_sample_format() -> SampleFormat;
+        /// the value of a silent sample
+        fn silence() -> Self {
+            Self::default()
+        }
+        /// the sample as a normalised value where full scale is `-1.0..=1.0`
+        fn to_normalised(self) -> f32;
+        /// the sample nearest to the given normalised value
+        fn from_normalised(value: f32) -> Self;
+    }
+
+    /// internal private trait allowing boxed host API specific stream info to be cloned and compared
+    pub trait HostApiSpecificStreamInfoPrivate {
+        /// clone the info into a new box
+        fn clone_box(&self) -> Box<dyn HostApiSpecificStreamInfo>;
+        /// whether the info is equal to `other`, which may be of another type
+        fn eq_any(&self, other: &dyn Any) -> bool;
+        /// the info as `Any`, for comparison
+        fn as_any(&self) -> &dyn Any;
+    }
+
+    impl<T> HostApiSpecificStreamInfoPrivate for T
+    where
+        T: HostApiSpecificStreamInfo + Clone + PartialEq + 'static,
+    {
+        fn clone_box(&self) -> Box<dyn HostApiSpecificStreamInfo> {
+            Box::new(self.clone())
+        }
+        fn eq_any(&self, other: &dyn Any) -> bool {
+            match other.downcast_ref::<T>() {
+                Some(other) => self == other,
+                None => false,
+            }
+        }
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
     }
 }
 
@@ -784,30 +1356,67 @@ impl private::SamplePrivate for f32 {
     fn to_sample_format() -> SampleFormat {
         SampleFormat::F32
     }
+    fn to_normalised(self) -> f32 {
+        self
+    }
+    fn from_normalised(value: f32) -> Self {
+        value
+    }
 }
 
 impl private::SamplePrivate for i32 {
     fn to_sample_format() -> SampleFormat {
         SampleFormat::I32
     }
+    fn to_normalised(self) -> f32 {
+        self as f32 / 2_147_483_648.0
+    }
+    fn from_normalised(value: f32) -> Self {
+        // `as` saturates at the bounds of the integer type.
+        (value * 2_147_483_648.0).round() as i32
+    }
 }
 
 impl private::SamplePrivate for i16 {
     fn to_sample_format() -> SampleFormat {
         SampleFormat::I16
     }
+    fn to_normalised(self) -> f32 {
+        self as f32 / 32_768.0
+    }
+    fn from_normalised(value: f32) -> Self {
+        // `as` saturates at the bounds of the integer type.
+        (value * 32_768.0).round() as i16
+    }
 }
 
 impl private::SamplePrivate for i8 {
     fn to_sample_format() -> SampleFormat {
         SampleFormat::I8
     }
+    fn to_normalised(self) -> f32 {
+        self as f32 / 128.0
+    }
+    fn from_normalised(value: f32) -> Self {
+        // `as` saturates at the bounds of the integer type.
+        (value * 128.0).round() as i8
+    }
 }
 
 impl private::SamplePrivate for u8 {
     fn to_sample_format() -> SampleFormat {
         SampleFormat::U8
     }
+    fn silence() -> Self {
+        0x80
+    }
+    fn to_normalised(self) -> f32 {
+        (self as f32 - 128.0) / 128.0
+    }
+    fn from_normalised(value: f32) -> Self {
+        // `as` saturates at the bounds of the integer type.
+        (value * 128.0 + 128.0).round() as u8
+    }
 }
 
 /// public trait to constraint pa::Stream for specific types
@@ -0,0 +1,258 @@
+//! Types for tracking audio devices that are added, removed or changed while an application is
+//! running.
+//!
+//! PortAudio only refreshes its list of devices when the library is initialised. See
+//! [**PortAudio::rescan_devices**](../struct.PortAudio.html#method.rescan_devices) for refreshing
+//! the list at runtime.
+
+use super::error::{Error, ErrorKind};
+use super::types::{DeviceIndex, HostApiTypeId, Time};
+use super::PortAudio;
+
+/// An owned copy of the information PortAudio holds about a single device at the time of a scan.
+///
+/// Unlike **DeviceInfo**, a **DeviceSnapshot** does not borrow from PortAudio and remains valid
+/// after the library is re-initialised.
+///
+/// A device is identified across scans by its `host_api`, `name` and `ordinal`, as its `index` may
+/// change whenever devices are added or removed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeviceSnapshot {
+    /// The index of the device at the time of the scan.
+    pub index: DeviceIndex,
+    /// The type of the host API through which the device is accessed.
+    pub host_api: HostApiTypeId,
+    /// The name of the device.
+    pub name: String,
+    /// Distinguishes devices that share both `host_api` and `name`, counting from `0` in order of
+    /// their index.
+    pub ordinal: usize,
+    /// Maximal number of input channels for this device
+    pub max_input_channels: i32,
+    /// maximal number of output channel for this device
+    pub max_output_channels: i32,
+    /// The default low latency for input with this device
+    pub default_low_input_latency: Time,
+    /// The default low latency for output with this device
+    pub default_low_output_latency: Time,
+    /// The default high latency for input with this device
+    pub default_high_input_latency: Time,
+    /// The default high latency for output with this device
+    pub default_high_output_latency: Time,
+    /// The default sample rate for this device
+    pub default_sample_rate: f64,
+}
+
+/// The difference between two scans of the available devices.
+///
+/// Produced by the [**PortAudio::rescan_devices**](../struct.PortAudio.html#method.rescan_devices)
+/// method.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeviceChanges {
+    /// Devices that are present in the new scan but were not present in the old one.
+    pub added: Vec<DeviceSnapshot>,
+    /// Devices that were present in the old scan but are no longer present.
+    pub removed: Vec<DeviceSnapshot>,
+    /// Devices present in both scans whose properties have changed, given as `(old, new)` pairs.
+    ///
+    /// A change of `index` alone is not considered a change.
+    pub changed: Vec<(DeviceSnapshot, DeviceSnapshot)>,
+}
+
+impl DeviceSnapshot {
+    /// Whether or not `self` and `other` describe the same physical or logical device.
+    pub fn is_same_device(&self, other: &DeviceSnapshot) -> bool {
+        self.host_api == other.host_api && self.name == other.name && self.ordinal == other.ordinal
+    }
+
+    /// Whether or not the properties of `self` and `other` are equal, ignoring their `index`.
+    fn has_same_properties(&self, other: &DeviceSnapshot) -> bool {
+        self.max_input_channels == other.max_input_channels
+            && self.max_output_channels == other.max_output_channels
+            && self.default_low_input_latency == other.default_low_input_latency
+            && self.default_low_output_latency == other.default_low_output_latency
+            && self.default_high_input_latency == other.default_high_input_latency
+            && self.default_high_output_latency == other.default_high_output_latency
+            && self.default_sample_rate == other.default_sample_rate
+    }
+}
+
+impl DeviceChanges {
+    /// Determine the changes between the `old` and `new` scans.
+    pub fn between(old: &[DeviceSnapshot], new: &[DeviceSnapshot]) -> Self {
+        let mut changes = DeviceChanges::default();
+        for old_device in old {
+            match new.iter().find(|d| d.is_same_device(old_device)) {
+                None => changes.removed.push(old_device.clone()),
+                Some(new_device) => {
+                    if !new_device.has_same_properties(old_device) {
+                        changes
+                            .changed
+                            .push((old_device.clone(), new_device.clone()));
+                    }
+                }
+            }
+        }
+        for new_device in new {
+            if !old.iter().any(|d| d.is_same_device(new_device)) {
+                changes.added.push(new_device.clone());
+            }
+        }
+        changes
+    }
+
+    /// Returns `true` if no devices were added, removed or changed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Take a snapshot of all devices currently known to the given **PortAudio** instance.
+pub fn snapshot(pa: &PortAudio) -> Result<Vec<DeviceSnapshot>, Error> {
+    let mut snapshots: Vec<DeviceSnapshot> = Vec::new();
+    for device in pa.devices()? {
+        let (index, info) = device?;
+        let host_api = match pa.host_api_info(info.host_api) {
+            Some(host_info) => host_info.host_type,
+            None => {
+                return Err(Error::with_context(
+                    ErrorKind::InvalidHostApi,
+                    "Pa_GetHostApiInfo",
+                ))
+            }
+        };
+        let ordinal = next_ordinal(&snapshots, host_api, info.name);
+        snapshots.push(DeviceSnapshot {
+            index,
+            host_api,
+            name: info.name.to_string(),
+            ordinal,
+            max_input_channels: info.max_input_channels,
+            max_output_channels: info.max_output_channels,
+            default_low_input_latency: info.default_low_input_latency,
+            default_low_output_latency: info.default_low_output_latency,
+            default_high_input_latency: info.default_high_input_latency,
+            default_high_output_latency: info.default_high_output_latency,
+            default_sample_rate: info.default_sample_rate,
+        });
+    }
+    Ok(snapshots)
+}
+
+/// The `ordinal` of the next device with the given `host_api` and `name` to be added to the
+/// `snapshots`.
+fn next_ordinal(snapshots: &[DeviceSnapshot], host_api: HostApiTypeId, name: &str) -> usize {
+    snapshots
+        .iter()
+        .filter(|d| d.host_api == host_api && d.name == name)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{next_ordinal, DeviceChanges, DeviceSnapshot};
+    use types::{DeviceIndex, HostApiTypeId};
+
+    /// A snapshot of a stereo output device with the given identity.
+    fn device(index: u32, host_api: HostApiTypeId, name: &str, ordinal: usize) -> DeviceSnapshot {
+        DeviceSnapshot {
+            index: DeviceIndex(index),
+            host_api,
+            name: name.to_string(),
+            ordinal,
+            max_input_channels: 0,
+            max_output_channels: 2,
+            default_low_input_latency: 0.0,
+            default_low_output_latency: 0.01,
+            default_high_input_latency: 0.0,
+            default_high_output_latency: 0.1,
+            default_sample_rate: 44_100.0,
+        }
+    }
+
+    #[test]
+    fn identical_scans_have_no_changes() {
+        let scan = vec![
+            device(0, HostApiTypeId::ALSA, "hw:0", 0),
+            device(1, HostApiTypeId::JACK, "system", 0),
+        ];
+        assert!(DeviceChanges::between(&scan, &scan).is_empty());
+    }
+
+    #[test]
+    fn added_removed_and_changed_devices() {
+        let kept = device(0, HostApiTypeId::ALSA, "hw:0", 0);
+        let removed = device(1, HostApiTypeId::ALSA, "hw:1", 0);
+        let old_changed = device(2, HostApiTypeId::JACK, "system", 0);
+        let old = vec![kept.clone(), removed.clone(), old_changed.clone()];
+
+        let mut new_changed = device(1, HostApiTypeId::JACK, "system", 0);
+        new_changed.default_sample_rate = 48_000.0;
+        let added = device(2, HostApiTypeId::ALSA, "USB Audio", 0);
+        let new = vec![kept, new_changed.clone(), added.clone()];
+
+        let changes = DeviceChanges::between(&old, &new);
+        assert_eq!(changes.added, vec![added]);
+        assert_eq!(changes.removed, vec![removed]);
+        assert_eq!(changes.changed, vec![(old_changed, new_changed)]);
+    }
+
+    #[test]
+    fn index_change_alone_is_not_a_change() {
+        let old = vec![
+            device(0, HostApiTypeId::ALSA, "hw:0", 0),
+            device(1, HostApiTypeId::ALSA, "hw:1", 0),
+        ];
+        let new = vec![
+            device(0, HostApiTypeId::ALSA, "hw:1", 0),
+            device(1, HostApiTypeId::ALSA, "hw:0", 0),
+        ];
+        assert!(DeviceChanges::between(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn devices_are_matched_by_host_api() {
+        let old = vec![device(0, HostApiTypeId::ALSA, "default", 0)];
+        let new = vec![device(0, HostApiTypeId::JACK, "default", 0)];
+        let changes = DeviceChanges::between(&old, &new);
+        assert_eq!(changes.removed, old);
+        assert_eq!(changes.added, new);
+        assert!(changes.changed.is_empty());
+    }
+
+    #[test]
+    fn devices_with_the_same_name_are_matched_by_ordinal() {
+        let first = device(0, HostApiTypeId::ALSA, "USB Audio", 0);
+        let second = device(1, HostApiTypeId::ALSA, "USB Audio", 1);
+        let old = vec![first.clone(), second.clone()];
+        // Unplugging one of two identical devices removes the last of them.
+        let new = vec![first.clone()];
+        let changes = DeviceChanges::between(&old, &new);
+        assert_eq!(changes.removed, vec![second.clone()]);
+        assert!(changes.added.is_empty() && changes.changed.is_empty());
+
+        let changes = DeviceChanges::between(&new, &old);
+        assert_eq!(changes.added, vec![second]);
+        assert!(changes.removed.is_empty() && changes.changed.is_empty());
+    }
+
+    #[test]
+    fn ordinals_count_devices_with_the_same_host_api_and_name() {
+        let mut snapshots = Vec::new();
+        for &(host_api, name) in &[
+            (HostApiTypeId::ALSA, "USB Audio"),
+            (HostApiTypeId::ALSA, "hw:0"),
+            (HostApiTypeId::ALSA, "USB Audio"),
+            (HostApiTypeId::JACK, "USB Audio"),
+            (HostApiTypeId::ALSA, "USB Audio"),
+        ] {
+            let ordinal = next_ordinal(&snapshots, host_api, name);
+            let index = snapshots.len() as u32;
+            snapshots.push(device(index, host_api, name, ordinal));
+        }
+        let ordinals: Vec<usize> = snapshots.iter().map(|d| d.ordinal).collect();
+        assert_eq!(ordinals, [0, 0, 1, 0, 2]);
+        assert!(!snapshots[0].is_same_device(&snapshots[2]));
+        assert!(!snapshots[0].is_same_device(&snapshots[3]));
+    }
+}
@@ -0,0 +1,410 @@
+//! Helpers for running a **Blocking** **Stream**'s I/O thread with real-time priority.
+//!
+//! PortAudio runs the callback of a **NonBlocking** **Stream** on a thread that the host API has
+//! already given an appropriate priority. A **Blocking** **Stream** is read from or written to on
+//! a thread owned by the user, which by default competes with every other thread on the system
+//! and may miss the stream's deadlines under load.
+//!
+//! [**promote_current_thread**](./fn.promote_current_thread.html) switches the calling thread to a
+//! real-time scheduling policy, falling back to a raised nice value where real-time scheduling is
+//! not permitted, as rtkit does for unprivileged processes. The returned **Guard** restores the
+//! thread's previous scheduling when dropped.
+//!
+//! ```no_run
+//! extern crate portaudio as pa;
+//! # fn main() {
+//! let pa = pa::PortAudio::new().unwrap();
+//! let settings = pa.default_output_stream_settings::<f32>(2, 44_100.0, 256).unwrap();
+//! let mut stream = pa.open_blocking_stream(settings).unwrap();
+//! let promotion = pa::rt::Promotion::for_stream(pa::rt::Policy::Fifo, &stream);
+//! let _guard = pa::rt::promote_current_thread(promotion).unwrap();
+//! stream.start().unwrap();
+//! # }
+//! ```
+//!
+//! Real-time scheduling is only supported on unix platforms and the nice fallback only on Linux
+//! and Android.
+
+use std::error::Error as StdError;
+use std::fmt;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use super::stream::Stream;
+
+/// The real-time scheduling policies that a thread may be promoted to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Policy {
+    /// `SCHED_FIFO`: the thread runs until it blocks or a higher priority thread becomes ready.
+    Fifo,
+    /// `SCHED_RR`: as `Fifo`, but threads of equal priority share the CPU in time slices.
+    RoundRobin,
+}
+
+/// Describes how to promote a thread.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Promotion {
+    /// The real-time scheduling policy to switch to.
+    pub policy: Policy,
+    /// The priority within the `policy`, which must lie within the range supported by the system.
+    pub priority: i32,
+    /// The nice value to apply instead if the thread is not permitted to use the real-time
+    /// `policy`, or `None` to fail with **PromoteError::PermissionDenied**.
+    pub nice_fallback: Option<i32>,
+}
+
+/// The scheduling applied by a successful promotion.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Applied {
+    /// The thread was switched to the given real-time policy and priority.
+    RealTime {
+        /// The policy the thread now runs with.
+        policy: Policy,
+        /// The priority the thread now runs with.
+        priority: i32,
+    },
+    /// Real-time scheduling was not permitted and the thread's nice value was set instead.
+    Nice(i32),
+}
+
+/// The reasons that promoting a thread may fail.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PromoteError {
+    /// Real-time scheduling is not supported on this platform.
+    Unsupported,
+    /// The requested priority lies outside of the range supported for the policy.
+    InvalidPriority {
+        /// The requested priority.
+        priority: i32,
+        /// The lowest priority supported for the policy.
+        min: i32,
+        /// The highest priority supported for the policy.
+        max: i32,
+    },
+    /// The thread is not permitted to use real-time scheduling and the nice fallback was either
+    /// disabled or also not permitted.
+    PermissionDenied {
+        /// The `errno` reported by the last attempt.
+        errno: i32,
+    },
+    /// A system call failed for some other reason.
+    Os {
+        /// The name of the failing function.
+        function: &'static str,
+        /// The `errno` reported by the function.
+        errno: i32,
+    },
+}
+
+/// Restores the promoted thread's previous scheduling when dropped.
+///
+/// The **Guard** must be dropped on the thread that was promoted and so is neither `Send` nor
+/// `Sync`.
+#[derive(Debug)]
+pub struct Guard {
+    applied: Applied,
+    previous: imp::Previous,
+    // Scheduling is per-thread, so the guard must stay on the thread it was created on.
+    not_send: PhantomData<*const ()>,
+}
+
+impl Promotion {
+    /// The nice value used as the fallback by default, matching that commonly granted by rtkit.
+    pub const DEFAULT_NICE_FALLBACK: i32 = -11;
+
+    /// Promote to the given `policy` and `priority`, falling back to the default nice value.
+    pub fn new(policy: Policy, priority: i32) -> Self {
+        Promotion {
+            policy,
+            priority,
+            nice_fallback: Some(Self::DEFAULT_NICE_FALLBACK),
+        }
+    }
+
+    /// Promote to the given `policy` with a priority chosen for a thread that must service a
+    /// buffer every `period`.
+    ///
+    /// Shorter periods leave less room for preemption and so are given higher priorities, while
+    /// staying below the top of the range so that system threads such as interrupt handlers are
+    /// not starved.
+    pub fn for_period(policy: Policy, period: Duration) -> Self {
+        let (min, max) = imp::priority_range(policy).unwrap_or((1, 99));
+        let fraction = if period <= Duration::from_millis(5) {
+            0.8
+        } else if period <= Duration::from_millis(20) {
+            0.7
+        } else {
+            0.6
+        };
+        let priority = min + ((max - min) as f64 * fraction).round() as i32;
+        Self::new(policy, priority)
+    }
+
+    /// Promote to the given `policy` with a priority chosen for the period of the given **Stream**.
+    ///
+    /// The period is taken to be the larger of the stream's input and output latency as reported
+    /// by **Stream::info**, as this is the deadline within which each read or write must complete.
+    pub fn for_stream<M, F>(policy: Policy, stream: &Stream<M, F>) -> Self {
+        let info = stream.info();
+        let latency = info.input_latency.max(info.output_latency);
+        let period = if latency.is_finite() && latency > 0.0 {
+            Duration::from_secs_f64(latency)
+        } else {
+            Duration::from_millis(20)
+        };
+        Self::for_period(policy, period)
+    }
+
+    /// Set the nice value to fall back to, or `None` to disable the fallback.
+    pub fn nice_fallback(self, nice_fallback: Option<i32>) -> Self {
+        Promotion {
+            nice_fallback,
+            ..self
+        }
+    }
+}
+
+impl Guard {
+    /// The scheduling applied by the promotion.
+    pub fn applied(&self) -> Applied {
+        self.applied
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        imp::restore(&self.previous);
+    }
+}
+
+impl fmt::Display for PromoteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PromoteError::Unsupported => {
+                write!(f, "Real-time scheduling is not supported on this platform")
+            }
+            PromoteError::InvalidPriority { priority, min, max } => write!(
+                f,
+                "Priority {} is outside of the supported range {}..={}",
+                priority, min, max
+            ),
+            PromoteError::PermissionDenied { errno } => write!(
+                f,
+                "Not permitted to raise the thread's priority (errno {})",
+                errno
+            ),
+            PromoteError::Os { function, errno } => {
+                write!(f, "{} failed (errno {})", function, errno)
+            }
+        }
+    }
+}
+
+impl StdError for PromoteError {}
+
+/// Promote the calling thread as described by the `promotion`.
+///
+/// Returns a **Guard** that restores the thread's previous scheduling when dropped.
+pub fn promote_current_thread(promotion: Promotion) -> Result<Guard, PromoteError> {
+    let (min, max) = imp::priority_range(promotion.policy)?;
+    if promotion.priority < min || promotion.priority > max {
+        return Err(PromoteError::InvalidPriority {
+            priority: promotion.priority,
+            min,
+            max,
+        });
+    }
+    let previous = imp::current()?;
+    let applied = match imp::set_real_time(promotion.policy, promotion.priority) {
+        Ok(()) => Applied::RealTime {
+            policy: promotion.policy,
+            priority: promotion.priority,
+        },
+        Err(PromoteError::PermissionDenied { errno }) => match promotion.nice_fallback {
+            Some(nice) => {
+                imp::set_nice(nice)?;
+                Applied::Nice(nice)
+            }
+            None => return Err(PromoteError::PermissionDenied { errno }),
+        },
+        Err(err) => return Err(err),
+    };
+    Ok(Guard {
+        applied,
+        previous,
+        not_send: PhantomData,
+    })
+}
+
+#[cfg(unix)]
+mod imp {
+    use super::{Policy, PromoteError};
+
+    /// The scheduling of a thread prior to its promotion.
+    #[derive(Debug)]
+    pub struct Previous {
+        policy: libc::c_int,
+        priority: libc::c_int,
+        nice: Option<libc::c_int>,
+    }
+
+    fn errno() -> i32 {
+        std::io::Error::last_os_error().raw_os_error().unwrap_or(0)
+    }
+
+    fn os_error(function: &'static str, errno: i32) -> PromoteError {
+        match errno {
+            libc::EPERM | libc::EACCES => PromoteError::PermissionDenied { errno },
+            _ => PromoteError::Os { function, errno },
+        }
+    }
+
+    fn policy_to_c(policy: Policy) -> libc::c_int {
+        match policy {
+            Policy::Fifo => libc::SCHED_FIFO,
+            Policy::RoundRobin => libc::SCHED_RR,
+        }
+    }
+
+    pub fn priority_range(policy: Policy) -> Result<(i32, i32), PromoteError> {
+        let policy = policy_to_c(policy);
+        let min = unsafe { libc::sched_get_priority_min(policy) };
+        if min == -1 {
+            return Err(os_error("sched_get_priority_min", errno()));
+        }
+        let max = unsafe { libc::sched_get_priority_max(policy) };
+        if max == -1 {
+            return Err(os_error("sched_get_priority_max", errno()));
+        }
+        Ok((min, max))
+    }
+
+    pub fn current() -> Result<Previous, PromoteError> {
+        let mut policy = 0;
+        let mut param: libc::sched_param = unsafe { std::mem::zeroed() };
+        let result =
+            unsafe { libc::pthread_getschedparam(libc::pthread_self(), &mut policy, &mut param) };
+        if result != 0 {
+            return Err(os_error("pthread_getschedparam", result));
+        }
+        Ok(Previous {
+            policy,
+            priority: param.sched_priority,
+            nice: nice::get(),
+        })
+    }
+
+    pub fn set_real_time(policy: Policy, priority: i32) -> Result<(), PromoteError> {
+        let mut param: libc::sched_param = unsafe { std::mem::zeroed() };
+        param.sched_priority = priority;
+        let policy = policy_to_c(policy) | reset_on_fork();
+        let result = unsafe { libc::pthread_setschedparam(libc::pthread_self(), policy, &param) };
+        match result {
+            0 => Ok(()),
+            errno => Err(os_error("pthread_setschedparam", errno)),
+        }
+    }
+
+    pub fn set_nice(value: i32) -> Result<(), PromoteError> {
+        nice::set(value)
+    }
+
+    pub fn restore(previous: &Previous) {
+        let mut param: libc::sched_param = unsafe { std::mem::zeroed() };
+        param.sched_priority = previous.priority;
+        unsafe { libc::pthread_setschedparam(libc::pthread_self(), previous.policy, &param) };
+        if let Some(value) = previous.nice {
+            let _ = nice::set(value);
+        }
+    }
+
+    /// Ask that children forked from the promoted thread don't inherit its real-time policy, as
+    /// rtkit requires.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn reset_on_fork() -> libc::c_int {
+        libc::SCHED_RESET_ON_FORK
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    fn reset_on_fork() -> libc::c_int {
+        0
+    }
+
+    /// On Linux the nice value is a per-thread attribute addressed by the thread id.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    mod nice {
+        use super::{errno, os_error, PromoteError};
+
+        fn thread_id() -> libc::id_t {
+            unsafe { libc::syscall(libc::SYS_gettid) as libc::id_t }
+        }
+
+        #[cfg(target_os = "linux")]
+        unsafe fn clear_errno() {
+            *libc::__errno_location() = 0;
+        }
+
+        #[cfg(target_os = "android")]
+        unsafe fn clear_errno() {
+            *libc::__errno() = 0;
+        }
+
+        pub fn get() -> Option<libc::c_int> {
+            // -1 is a valid nice value, so errno must be cleared to detect failure.
+            unsafe { clear_errno() };
+            let value = unsafe { libc::getpriority(libc::PRIO_PROCESS, thread_id()) };
+            if value == -1 && errno() != 0 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        pub fn set(value: i32) -> Result<(), PromoteError> {
+            match unsafe { libc::setpriority(libc::PRIO_PROCESS, thread_id(), value) } {
+                0 => Ok(()),
+                _ => Err(os_error("setpriority", errno())),
+            }
+        }
+    }
+
+    /// Elsewhere the nice value applies to the whole process and so is not used as a fallback.
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    mod nice {
+        use super::PromoteError;
+
+        pub fn get() -> Option<libc::c_int> {
+            None
+        }
+
+        pub fn set(_value: i32) -> Result<(), PromoteError> {
+            Err(PromoteError::Unsupported)
+        }
+    }
+}
+
+#[cfg(not(unix))]
+mod imp {
+    use super::{Policy, PromoteError};
+
+    #[derive(Debug)]
+    pub struct Previous;
+
+    pub fn priority_range(_policy: Policy) -> Result<(i32, i32), PromoteError> {
+        Err(PromoteError::Unsupported)
+    }
+
+    pub fn current() -> Result<Previous, PromoteError> {
+        Err(PromoteError::Unsupported)
+    }
+
+    pub fn set_real_time(_policy: Policy, _priority: i32) -> Result<(), PromoteError> {
+        Err(PromoteError::Unsupported)
+    }
+
+    pub fn set_nice(_value: i32) -> Result<(), PromoteError> {
+        Err(PromoteError::Unsupported)
+    }
+
+    pub fn restore(_previous: &Previous) {}
+}
@@ -0,0 +1,393 @@
+//! Selection of a device from an ordered list of preferences.
+//!
+//! The primary type of interest is [**DeviceSelector**](./struct.DeviceSelector.html).
+
+use std::fmt;
+
+use super::error::{Error, ErrorKind};
+use super::stream::Parameters;
+use super::types::{DeviceIndex, DeviceInfo, Direction, HostApiTypeId};
+use super::{PortAudio, Sample};
+
+/// A set of requirements that a device must meet in order to be selected by a
+/// **DeviceSelector**.
+///
+/// Every requirement that is set must be met. A **Criteria** with no requirements set matches any
+/// device.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Criteria {
+    /// If `true`, only the default device of the default host API is considered.
+    pub system_default: bool,
+    /// The device's name must contain this string.
+    pub name: Option<String>,
+    /// The device must be accessed through this host API.
+    pub host_api: Option<HostApiTypeId>,
+    /// The device must provide at least this many channels in the selector's direction.
+    ///
+    /// The device must always provide at least the number of channels requested from the
+    /// **DeviceSelector**, regardless of this value.
+    pub min_channels: Option<i32>,
+    /// The device must support this sample rate.
+    pub sample_rate: Option<f64>,
+}
+
+/// Resolves an ordered list of **Criteria** to the first device that meets them.
+///
+/// Each **Criteria** is tried in the order in which it was added. The first device meeting a
+/// **Criteria** is selected, along with **StreamParameters** ready for opening a stream with it.
+///
+/// ```no_run
+/// extern crate portaudio as pa;
+/// # fn main() {
+/// use pa::selector::{Criteria, DeviceSelector};
+///
+/// let pa = pa::PortAudio::new().unwrap();
+/// let selection = DeviceSelector::output(2)
+///     .rule(Criteria::named("Scarlett 2i2").sample_rate(48_000.0))
+///     .rule(Criteria::named("USB Audio").host_api(pa::HostApiTypeId::ALSA))
+///     .rule(Criteria::system_default())
+///     .select::<f32>(&pa)
+///     .unwrap();
+/// println!("Selected {:?} via rule {}", selection.device, selection.rule);
+/// for rejection in &selection.rejections {
+///     println!("{}", rejection);
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeviceSelector {
+    direction: Direction,
+    channels: i32,
+    rules: Vec<Criteria>,
+}
+
+/// The device chosen by a **DeviceSelector**.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Selection<S> {
+    /// The index of the **Criteria** that the device met.
+    pub rule: usize,
+    /// The selected device.
+    pub device: DeviceIndex,
+    /// Parameters for opening a stream with the selected device.
+    ///
+    /// The parameters use the device's default low latency and assume interleaved audio data.
+    pub params: Parameters<S>,
+    /// Why each of the preceding rules failed to select a device.
+    pub rejections: Vec<Rejection>,
+}
+
+/// Describes why a single rule failed to select a device.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rejection {
+    /// The index of the **Criteria** that was rejected.
+    pub rule: usize,
+    /// The reason for the rejection.
+    pub reason: RejectionReason,
+}
+
+/// The reason for which a rule failed to select a device.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RejectionReason {
+    /// There is no default device for the selector's direction.
+    NoDefaultDevice(Error),
+    /// No device matched the rule's `name` and `host_api`.
+    NoMatchingDevice,
+    /// A matching device does not provide enough channels.
+    TooFewChannels {
+        /// The device that was considered.
+        device: DeviceIndex,
+        /// The number of channels the device provides.
+        available: i32,
+        /// The number of channels that were required.
+        required: i32,
+    },
+    /// A matching device does not support the required sample rate.
+    UnsupportedSampleRate {
+        /// The device that was considered.
+        device: DeviceIndex,
+        /// The sample rate that was required.
+        sample_rate: f64,
+        /// The error returned by PortAudio when checking the format.
+        error: Error,
+    },
+    /// PortAudio returned an error while the rule was being evaluated.
+    Error(Error),
+}
+
+/// Returned by **DeviceSelector::select** when none of the rules selected a device.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelectionError {
+    /// Why each of the rules failed to select a device.
+    pub rejections: Vec<Rejection>,
+}
+
+impl Criteria {
+    /// A **Criteria** that matches any device.
+    pub fn any() -> Self {
+        Criteria::default()
+    }
+
+    /// A **Criteria** that matches only the default device of the default host API.
+    pub fn system_default() -> Self {
+        Criteria {
+            system_default: true,
+            ..Criteria::default()
+        }
+    }
+
+    /// A **Criteria** that matches devices whose name contains the given string.
+    pub fn named<N>(name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Criteria {
+            name: Some(name.into()),
+            ..Criteria::default()
+        }
+    }
+
+    /// Also require that the device is accessed through the given host API.
+    pub fn host_api(mut self, host_api: HostApiTypeId) -> Self {
+        self.host_api = Some(host_api);
+        self
+    }
+
+    /// Also require that the device provides at least the given number of channels.
+    pub fn min_channels(mut self, channels: i32) -> Self {
+        self.min_channels = Some(channels);
+        self
+    }
+
+    /// Also require that the device supports the given sample rate.
+    pub fn sample_rate(mut self, sample_rate: f64) -> Self {
+        self.sample_rate = Some(sample_rate);
+        self
+    }
+
+    /// Whether or not the given device matches the `name` and `host_api` requirements.
+    fn matches(&self, info: &DeviceInfo, host_api: HostApiTypeId) -> bool {
+        let name_matches = match self.name {
+            Some(ref name) => info.name.contains(name.as_str()),
+            None => true,
+        };
+        let host_api_matches = match self.host_api {
+            Some(id) => id == host_api,
+            None => true,
+        };
+        name_matches && host_api_matches
+    }
+}
+
+impl DeviceSelector {
+    /// Construct a selector with no rules for the given direction.
+    ///
+    /// `channels` is the channel count of the produced **StreamParameters**. Only devices that
+    /// provide at least this many channels in the given direction are selected.
+    pub fn new(direction: Direction, channels: i32) -> Self {
+        DeviceSelector {
+            direction,
+            channels,
+            rules: Vec::new(),
+        }
+    }
+
+    /// Construct a selector with no rules for selecting an input device.
+    pub fn input(channels: i32) -> Self {
+        Self::new(Direction::Input, channels)
+    }
+
+    /// Construct a selector with no rules for selecting an output device.
+    pub fn output(channels: i32) -> Self {
+        Self::new(Direction::Output, channels)
+    }
+
+    /// Append a rule, to be tried after all previously added rules.
+    pub fn rule(mut self, criteria: Criteria) -> Self {
+        self.rules.push(criteria);
+        self
+    }
+
+    /// The rules in the order in which they are tried.
+    pub fn rules(&self) -> &[Criteria] {
+        &self.rules
+    }
+
+    /// Try each rule in order and return the first device that meets one.
+    ///
+    /// Returns a **SelectionError** describing why each rule failed if no device is selected.
+    pub fn select<S>(&self, pa: &PortAudio) -> Result<Selection<S>, SelectionError>
+    where
+        S: Sample,
+    {
+        let mut rejections = Vec::new();
+        for (rule, criteria) in self.rules.iter().enumerate() {
+            match self.try_rule::<S>(pa, criteria) {
+                Ok((device, params)) => {
+                    return Ok(Selection {
+                        rule,
+                        device,
+                        params,
+                        rejections,
+                    })
+                }
+                Err(reasons) => {
+                    rejections.extend(reasons.into_iter().map(|reason| Rejection { rule, reason }))
+                }
+            }
+        }
+        Err(SelectionError { rejections })
+    }
+
+    /// Find the first device meeting the given `criteria`, or the reasons for which each
+    /// candidate device was rejected.
+    fn try_rule<S>(
+        &self,
+        pa: &PortAudio,
+        criteria: &Criteria,
+    ) -> Result<(DeviceIndex, Parameters<S>), Vec<RejectionReason>>
+    where
+        S: Sample,
+    {
+        let candidates = if criteria.system_default {
+            let default_device = match self.direction {
+                Direction::Input => pa.default_input_device(),
+                Direction::Output => pa.default_output_device(),
+            };
+            match default_device {
+                Ok(device) => vec![device],
+                Err(err) => return Err(vec![RejectionReason::NoDefaultDevice(err)]),
+            }
+        } else {
+            let total = pa
+                .device_count()
+                .map_err(|err| vec![RejectionReason::Error(err)])?;
+            (0..total).map(DeviceIndex).collect()
+        };
+
+        let mut reasons = Vec::new();
+        for device in candidates {
+            let info = pa
+                .device_info(device)
+                .map_err(|err| vec![RejectionReason::Error(err)])?;
+            let host_api = match pa.host_api_info(info.host_api) {
+                Some(host_info) => host_info.host_type,
+                None => {
+                    return Err(vec![RejectionReason::Error(Error::with_context(
+                        ErrorKind::InvalidHostApi,
+                        "Pa_GetHostApiInfo",
+                    ))])
+                }
+            };
+            // Devices that can't be used in this direction at all are not considered candidates.
+            if !criteria.matches(&info, host_api) || self.channels_of(&info) == 0 {
+                continue;
+            }
+            match self.check_device::<S>(pa, criteria, device, &info) {
+                Ok(params) => return Ok((device, params)),
+                Err(reason) => reasons.push(reason),
+            }
+        }
+        if reasons.is_empty() {
+            reasons.push(RejectionReason::NoMatchingDevice);
+        }
+        Err(reasons)
+    }
+
+    /// The maximum number of channels the device provides in the selector's direction.
+    fn channels_of(&self, info: &DeviceInfo) -> i32 {
+        match self.direction {
+            Direction::Input => info.max_input_channels,
+            Direction::Output => info.max_output_channels,
+        }
+    }
+
+    /// Check the channel and sample rate requirements against a device matching `criteria`.
+    fn check_device<S>(
+        &self,
+        pa: &PortAudio,
+        criteria: &Criteria,
+        device: DeviceIndex,
+        info: &DeviceInfo,
+    ) -> Result<Parameters<S>, RejectionReason>
+    where
+        S: Sample,
+    {
+        const INTERLEAVED: bool = true;
+        let available = self.channels_of(info);
+        let latency = match self.direction {
+            Direction::Input => info.default_low_input_latency,
+            Direction::Output => info.default_low_output_latency,
+        };
+        let required = ::std::cmp::max(self.channels, criteria.min_channels.unwrap_or(0));
+        if available < required {
+            return Err(RejectionReason::TooFewChannels {
+                device,
+                available,
+                required,
+            });
+        }
+        let params = Parameters::new(device, self.channels, INTERLEAVED, latency);
+        if let Some(sample_rate) = criteria.sample_rate {
+            let supported = match self.direction {
+                Direction::Input => pa.is_input_format_supported(params.clone(), sample_rate),
+                Direction::Output => pa.is_output_format_supported(params.clone(), sample_rate),
+            };
+            if let Err(error) = supported {
+                return Err(RejectionReason::UnsupportedSampleRate {
+                    device,
+                    sample_rate,
+                    error,
+                });
+            }
+        }
+        Ok(params)
+    }
+}
+
+impl fmt::Display for RejectionReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RejectionReason::NoDefaultDevice(ref err) => {
+                write!(f, "no default device is available: {}", err)
+            }
+            RejectionReason::NoMatchingDevice => write!(f, "no device matched"),
+            RejectionReason::TooFewChannels {
+                device,
+                available,
+                required,
+            } => write!(
+                f,
+                "device {} provides {} channels but {} are required",
+                device.0, available, required
+            ),
+            RejectionReason::UnsupportedSampleRate {
+                device,
+                sample_rate,
+                ref error,
+            } => write!(
+                f,
+                "device {} does not support a sample rate of {}hz: {}",
+                device.0, sample_rate, error
+            ),
+            RejectionReason::Error(ref err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "rule {}: {}", self.rule, self.reason)
+    }
+}
+
+impl fmt::Display for SelectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no device met any of the selection rules")?;
+        for rejection in &self.rejections {
+            write!(f, "; {}", rejection)?;
+        }
+        Ok(())
+    }
+}
+
+impl ::std::error::Error for SelectionError {}
@@ -0,0 +1,465 @@
+//! A compact textual representation of a device, for use in command line flags and config files.
+//!
+//! The primary type of interest is [**DeviceSpec**](./struct.DeviceSpec.html).
+
+use std::fmt;
+use std::str::FromStr;
+
+use super::error::Error;
+use super::types::{DeviceIndex, Direction, HostApiTypeId};
+use super::PortAudio;
+
+/// Names a device in one of the following forms:
+///
+/// - `default` - the default device of the default host API for the requested direction.
+/// - `index:N` - the device with the **DeviceIndex** `N`.
+/// - `[host-api:]name[#ordinal]` - a device by name, e.g. `alsa:hw:1,0`, `jack:system` or
+///   `USB Audio#1`.
+///
+/// The optional host API prefix is one of the case-insensitive names `alsa`, `jack`, `oss`,
+/// `coreaudio`, `wasapi`, `asio`, `mme`, `directsound`, `wdmks`, `al`, `beos`, `soundmanager`,
+/// `audiosciencehpi`, `audioio`, `pulseaudio`, `sndio` or `indevelopment`. Host APIs unknown to
+/// this version of rust-portaudio may be given by their numeric type id, e.g. `hostapi-42`.
+/// Anything else before the first `:` is considered part of the name. An empty prefix (e.g.
+/// `:default`) may be used to refer to a device whose name would otherwise be mistaken for one of
+/// the other forms.
+///
+/// A name refers to the devices whose name is equal to it or, if there are none, the devices
+/// whose name contains it. The optional `#ordinal` selects between several such devices, counting
+/// from `0` in order of their index.
+///
+/// ```
+/// extern crate portaudio as pa;
+/// # fn main() {
+/// use pa::spec::DeviceSpec;
+///
+/// let spec: DeviceSpec = "alsa:hw:1,0".parse().unwrap();
+/// assert_eq!(
+///     spec,
+///     DeviceSpec::Name {
+///         host_api: Some(pa::HostApiTypeId::ALSA),
+///         name: "hw:1,0".to_string(),
+///         ordinal: 0,
+///     }
+/// );
+/// assert_eq!(spec.to_string(), "alsa:hw:1,0");
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum DeviceSpec {
+    /// The default device of the default host API for the requested direction.
+    Default,
+    /// The device at the given index.
+    Index(DeviceIndex),
+    /// A device by name.
+    Name {
+        /// The host API through which the device must be accessed, if any.
+        host_api: Option<HostApiTypeId>,
+        /// The name, or part of the name, of the device.
+        name: String,
+        /// Selects between several devices matching the name, counting from `0`.
+        ordinal: usize,
+    },
+}
+
+/// Returned when a string can't be parsed as a **DeviceSpec**.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseDeviceSpecError {
+    /// The string was empty.
+    Empty,
+    /// The `N` in `index:N` is not a valid device index.
+    InvalidIndex(String),
+    /// The name following a host API prefix was empty.
+    EmptyName,
+}
+
+/// Returned when a **DeviceSpec** can't be resolved to a device.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ResolveDeviceSpecError {
+    /// There is no default device for the requested direction.
+    NoDefaultDevice(Error),
+    /// The index is greater than or equal to the number of devices.
+    IndexOutOfRange {
+        /// The requested index.
+        index: DeviceIndex,
+        /// The number of available devices.
+        device_count: u32,
+    },
+    /// Fewer devices than the requested ordinal match the name.
+    NoMatchingDevice {
+        /// The spec that was being resolved.
+        spec: DeviceSpec,
+        /// The number of devices that did match.
+        matches: usize,
+    },
+    /// PortAudio returned an error while resolving the spec.
+    Error(Error),
+}
+
+/// The host API names recognised as prefixes.
+const HOST_API_NAMES: &[(HostApiTypeId, &str)] = &[
+    (HostApiTypeId::InDevelopment, "indevelopment"),
+    (HostApiTypeId::DirectSound, "directsound"),
+    (HostApiTypeId::MME, "mme"),
+    (HostApiTypeId::ASIO, "asio"),
+    (HostApiTypeId::SoundManager, "soundmanager"),
+    (HostApiTypeId::CoreAudio, "coreaudio"),
+    (HostApiTypeId::OSS, "oss"),
+    (HostApiTypeId::ALSA, "alsa"),
+    (HostApiTypeId::AL, "al"),
+    (HostApiTypeId::BeOS, "beos"),
+    (HostApiTypeId::WDMKS, "wdmks"),
+    (HostApiTypeId::JACK, "jack"),
+    (HostApiTypeId::WASAPI, "wasapi"),
+    (HostApiTypeId::AudioScienceHPI, "audiosciencehpi"),
+    (HostApiTypeId::AudioIO, "audioio"),
+    (HostApiTypeId::PulseAudio, "pulseaudio"),
+    (HostApiTypeId::Sndio, "sndio"),
+];
+
+const DEFAULT: &str = "default";
+const INDEX_PREFIX: &str = "index";
+const UNKNOWN_HOST_API_PREFIX: &str = "hostapi-";
+
+/// Look up a host API by its prefix name, ignoring case.
+fn host_api_from_name(name: &str) -> Option<HostApiTypeId> {
+    if let Some(id) = name.strip_prefix(UNKNOWN_HOST_API_PREFIX) {
+        let id = id.parse::<::ffi::PaHostApiTypeId>().ok()?;
+        return Some(HostApiTypeId::from(id));
+    }
+    HOST_API_NAMES
+        .iter()
+        .find(|&&(_, n)| n.eq_ignore_ascii_case(name))
+        .map(|&(id, _)| id)
+}
+
+/// Write the prefix name of the given host API.
+fn write_host_api_name(f: &mut fmt::Formatter, host_api: HostApiTypeId) -> fmt::Result {
+    match HOST_API_NAMES.iter().find(|&&(id, _)| id == host_api) {
+        Some(&(_, name)) => write!(f, "{}", name),
+        None => {
+            let id: ::ffi::PaHostApiTypeId = host_api.into();
+            write!(f, "{}{}", UNKNOWN_HOST_API_PREFIX, id)
+        }
+    }
+}
+
+/// Split a trailing `#ordinal` from the given name, if there is one.
+fn split_ordinal(s: &str) -> (&str, Option<usize>) {
+    if let Some(hash) = s.rfind('#') {
+        let digits = &s[hash + 1..];
+        if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+            if let Ok(ordinal) = digits.parse() {
+                return (&s[..hash], Some(ordinal));
+            }
+        }
+    }
+    (s, None)
+}
+
+impl DeviceSpec {
+    /// Resolve the spec to the index of a device known to the given **PortAudio** instance.
+    ///
+    /// The `direction` is only used to determine the default device for **DeviceSpec::Default**.
+    pub fn resolve(
+        &self,
+        pa: &PortAudio,
+        direction: Direction,
+    ) -> Result<DeviceIndex, ResolveDeviceSpecError> {
+        match *self {
+            DeviceSpec::Default => {
+                let device = match direction {
+                    Direction::Input => pa.default_input_device(),
+                    Direction::Output => pa.default_output_device(),
+                };
+                device.map_err(ResolveDeviceSpecError::NoDefaultDevice)
+            }
+            DeviceSpec::Index(index) => {
+                let device_count = pa.device_count().map_err(ResolveDeviceSpecError::Error)?;
+                if index.0 < device_count {
+                    Ok(index)
+                } else {
+                    Err(ResolveDeviceSpecError::IndexOutOfRange {
+                        index,
+                        device_count,
+                    })
+                }
+            }
+            DeviceSpec::Name {
+                host_api,
+                ref name,
+                ordinal,
+            } => {
+                let mut exact = Vec::new();
+                let mut partial = Vec::new();
+                for device in pa.devices().map_err(ResolveDeviceSpecError::Error)? {
+                    let (index, info) = device.map_err(ResolveDeviceSpecError::Error)?;
+                    if let Some(host_api) = host_api {
+                        match pa.host_api_info(info.host_api) {
+                            Some(ref host_info) if host_info.host_type == host_api => (),
+                            _ => continue,
+                        }
+                    }
+                    if info.name == name.as_str() {
+                        exact.push(index);
+                    } else if info.name.contains(name.as_str()) {
+                        partial.push(index);
+                    }
+                }
+                let matches = if exact.is_empty() { partial } else { exact };
+                match matches.get(ordinal) {
+                    Some(&index) => Ok(index),
+                    None => Err(ResolveDeviceSpecError::NoMatchingDevice {
+                        spec: self.clone(),
+                        matches: matches.len(),
+                    }),
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for DeviceSpec {
+    type Err = ParseDeviceSpecError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseDeviceSpecError::Empty);
+        }
+        if s == DEFAULT {
+            return Ok(DeviceSpec::Default);
+        }
+        let (host_api, rest) = match s.find(':') {
+            Some(0) => (None, &s[1..]),
+            Some(colon) => {
+                let prefix = &s[..colon];
+                let rest = &s[colon + 1..];
+                if prefix == INDEX_PREFIX {
+                    return match rest.parse() {
+                        Ok(index) => Ok(DeviceSpec::Index(DeviceIndex(index))),
+                        Err(_) => Err(ParseDeviceSpecError::InvalidIndex(rest.to_string())),
+                    };
+                }
+                match host_api_from_name(prefix) {
+                    Some(host_api) => (Some(host_api), rest),
+                    None => (None, s),
+                }
+            }
+            None => (None, s),
+        };
+        let (name, ordinal) = split_ordinal(rest);
+        if name.is_empty() {
+            return Err(ParseDeviceSpecError::EmptyName);
+        }
+        Ok(DeviceSpec::Name {
+            host_api,
+            name: name.to_string(),
+            ordinal: ordinal.unwrap_or(0),
+        })
+    }
+}
+
+impl fmt::Display for DeviceSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DeviceSpec::Default => write!(f, "{}", DEFAULT),
+            DeviceSpec::Index(DeviceIndex(index)) => write!(f, "{}:{}", INDEX_PREFIX, index),
+            DeviceSpec::Name {
+                host_api,
+                ref name,
+                ordinal,
+            } => {
+                match host_api {
+                    Some(host_api) => {
+                        write_host_api_name(f, host_api)?;
+                        write!(f, ":")?;
+                    }
+                    None => {
+                        // Use an empty prefix if the name would otherwise parse as another form.
+                        let is_ambiguous = name == DEFAULT
+                            || name.starts_with(':')
+                            || match name.find(':') {
+                                Some(colon) => {
+                                    let prefix = &name[..colon];
+                                    prefix == INDEX_PREFIX || host_api_from_name(prefix).is_some()
+                                }
+                                None => false,
+                            };
+                        if is_ambiguous {
+                            write!(f, ":")?;
+                        }
+                    }
+                }
+                write!(f, "{}", name)?;
+                // A name that itself ends in `#N` must always be followed by the ordinal.
+                if ordinal > 0 || split_ordinal(name).1.is_some() {
+                    write!(f, "#{}", ordinal)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for ParseDeviceSpecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseDeviceSpecError::Empty => write!(f, "the device spec is empty"),
+            ParseDeviceSpecError::InvalidIndex(ref index) => write!(
+                f,
+                "`{}` is not a valid device index (expected `index:N` where N is a number)",
+                index
+            ),
+            ParseDeviceSpecError::EmptyName => {
+                write!(f, "the device spec has a host API but no device name")
+            }
+        }
+    }
+}
+
+impl fmt::Display for ResolveDeviceSpecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ResolveDeviceSpecError::NoDefaultDevice(ref err) => {
+                write!(f, "no default device is available: {}", err)
+            }
+            ResolveDeviceSpecError::IndexOutOfRange {
+                index,
+                device_count,
+            } => write!(
+                f,
+                "device index {} is out of range, there are only {} devices",
+                index.0, device_count
+            ),
+            ResolveDeviceSpecError::NoMatchingDevice {
+                ref spec,
+                matches: 0,
+            } => write!(f, "no device matches `{}`", spec),
+            ResolveDeviceSpecError::NoMatchingDevice { ref spec, matches } => write!(
+                f,
+                "the ordinal in `{}` is out of range as only {} matching devices were found",
+                spec, matches
+            ),
+            ResolveDeviceSpecError::Error(ref err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl ::std::error::Error for ParseDeviceSpecError {}
+impl ::std::error::Error for ResolveDeviceSpecError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{DeviceSpec, ParseDeviceSpecError};
+    use types::{DeviceIndex, HostApiTypeId};
+
+    fn name(host_api: Option<HostApiTypeId>, name: &str, ordinal: usize) -> DeviceSpec {
+        DeviceSpec::Name {
+            host_api,
+            name: name.to_string(),
+            ordinal,
+        }
+    }
+
+    fn parse(s: &str) -> Result<DeviceSpec, ParseDeviceSpecError> {
+        s.parse()
+    }
+
+    /// Check that `spec` is displayed as `s` and that `s` parses back to `spec`.
+    fn assert_round_trip(spec: DeviceSpec, s: &str) {
+        assert_eq!(spec.to_string(), s);
+        assert_eq!(parse(s), Ok(spec));
+    }
+
+    #[test]
+    fn default_and_index() {
+        assert_round_trip(DeviceSpec::Default, "default");
+        assert_round_trip(DeviceSpec::Index(DeviceIndex(3)), "index:3");
+    }
+
+    #[test]
+    fn names_with_host_api() {
+        assert_round_trip(name(Some(HostApiTypeId::ALSA), "hw:1,0", 0), "alsa:hw:1,0");
+        assert_round_trip(
+            name(Some(HostApiTypeId::JACK), "system", 2),
+            "jack:system#2",
+        );
+        assert_round_trip(name(None, "USB Audio", 1), "USB Audio#1");
+        assert_eq!(
+            parse("ALSA:hw:0"),
+            Ok(name(Some(HostApiTypeId::ALSA), "hw:0", 0))
+        );
+        assert_eq!(parse("USB Audio#0"), Ok(name(None, "USB Audio", 0)));
+    }
+
+    #[test]
+    fn unknown_prefix_is_part_of_name() {
+        assert_round_trip(name(None, "hw:1,0", 0), "hw:1,0");
+        assert_round_trip(name(None, "Speakers (Realtek)", 0), "Speakers (Realtek)");
+    }
+
+    #[test]
+    fn ambiguous_names_have_empty_prefix() {
+        assert_round_trip(name(None, "default", 0), ":default");
+        assert_round_trip(name(None, "index:3", 0), ":index:3");
+        assert_round_trip(name(None, "index:front", 0), ":index:front");
+        assert_round_trip(name(None, "alsa:hw:0", 0), ":alsa:hw:0");
+        assert_round_trip(name(None, "JACK:system", 0), ":JACK:system");
+        assert_round_trip(name(None, "hostapi-42:x", 0), ":hostapi-42:x");
+        assert_round_trip(name(None, ":x", 0), "::x");
+        // Names that merely start like a prefix aren't ambiguous.
+        assert_round_trip(name(None, "index", 0), "index");
+        assert_round_trip(name(None, "alsa", 0), "alsa");
+        assert_round_trip(
+            name(Some(HostApiTypeId::ALSA), "default", 0),
+            "alsa:default",
+        );
+    }
+
+    #[test]
+    fn names_ending_in_ordinal_syntax() {
+        assert_round_trip(name(None, "Card#2", 0), "Card#2#0");
+        assert_round_trip(name(None, "Card#2", 1), "Card#2#1");
+        assert_round_trip(
+            name(Some(HostApiTypeId::ALSA), "Card#2", 0),
+            "alsa:Card#2#0",
+        );
+        // A `#` not followed by digits is part of the name.
+        assert_round_trip(name(None, "Card#", 0), "Card#");
+        assert_round_trip(name(None, "Card#A", 0), "Card#A");
+        assert_eq!(parse("Card#2"), Ok(name(None, "Card", 2)));
+    }
+
+    #[test]
+    fn unknown_host_api_by_type_id() {
+        let host_api = HostApiTypeId::Unknown(42);
+        assert_round_trip(name(Some(host_api), "device", 0), "hostapi-42:device");
+        // Known host APIs given by their type id are displayed by name.
+        assert_eq!(
+            parse("hostapi-8:hw:0"),
+            Ok(name(Some(HostApiTypeId::ALSA), "hw:0", 0))
+        );
+        // A malformed type id is part of the name.
+        assert_eq!(parse("hostapi-x:y"), Ok(name(None, "hostapi-x:y", 0)));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(parse(""), Err(ParseDeviceSpecError::Empty));
+        assert_eq!(
+            parse("index:abc"),
+            Err(ParseDeviceSpecError::InvalidIndex("abc".to_string()))
+        );
+        assert_eq!(
+            parse("index:"),
+            Err(ParseDeviceSpecError::InvalidIndex("".to_string()))
+        );
+        assert_eq!(
+            parse("index:-1"),
+            Err(ParseDeviceSpecError::InvalidIndex("-1".to_string()))
+        );
+        assert_eq!(parse("alsa:"), Err(ParseDeviceSpecError::EmptyName));
+        assert_eq!(parse("alsa:#1"), Err(ParseDeviceSpecError::EmptyName));
+        assert_eq!(parse(":"), Err(ParseDeviceSpecError::EmptyName));
+        assert_eq!(parse("#1"), Err(ParseDeviceSpecError::EmptyName));
+    }
+}
@@ -5,12 +5,13 @@
 
 use ffi;
 use libc;
-use num::FromPrimitive;
 use std::os::raw;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::{self, ptr};
 
-use super::error::Error;
-use super::types::{DeviceIndex, DeviceKind, SampleFormat, SampleFormatFlags, Time};
+use super::error::{Error, ErrorKind};
+use super::private::HostApiSpecificStreamInfoPrivate;
+use super::types::{DeviceIndex, DeviceKind, HostApiTypeId, SampleFormat, SampleFormatFlags, Time};
 use super::Sample;
 
 pub use self::callback_flags::CallbackFlags;
@@ -37,7 +38,12 @@ pub trait Flow {
     /// The type of buffer(s) necessary for transferring audio in a Blocking stream.
     type Buffer;
     /// The arguments passed to the non-blocking stream callback.
-    type CallbackArgs;
+    ///
+    /// The arguments borrow the buffers PortAudio passes to the callback for the lifetime `'a` of
+    /// a single invocation, so they can't escape the callback.
+    type CallbackArgs<'a>
+    where
+        Self: 'a;
     /// Timing information for the buffer passed to the stream callback.
     type CallbackTimeInfo;
     /// Construct a new **Self::Buffer**.
@@ -51,7 +57,12 @@ pub trait Flow {
     );
     /// Constructs the **Flow**'s associated **CallbackArgs** from the non-blocking C API stream
     /// parameters.
-    fn new_callback_args(
+    ///
+    /// # Safety
+    ///
+    /// The pointers must be those passed by PortAudio to the stream callback for a stream opened
+    /// with this **Flow**'s parameters.
+    unsafe fn new_callback_args<'a>(
         input: *const raw::c_void,
         output: *mut raw::c_void,
         frame_count: raw::c_ulong,
@@ -59,7 +70,32 @@ pub trait Flow {
         flags: ffi::PaStreamCallbackFlags,
         in_channels: i32,
         out_channels: i32,
-    ) -> Self::CallbackArgs;
+    ) -> Self::CallbackArgs<'a>;
+    /// The **OutputOptions** of the **Flow**'s output, or `None` if it has no output.
+    fn output_options(&self) -> Option<OutputOptions>;
+    /// Check that the host API specific stream info of each direction applies to the host API of
+    /// its device. See **Parameters::check_host_api_specific_stream_info**.
+    fn check_host_api_specific_stream_info(&self, _context: &'static str) -> Result<(), Error> {
+        Ok(())
+    }
+    /// Prepare the output buffer from the non-blocking C API stream parameters according to the
+    /// given **OutputOptions** before it is passed to the callback.
+    fn prepare_output(
+        output: *mut raw::c_void,
+        frame_count: raw::c_ulong,
+        out_channels: i32,
+        options: &OutputOptions,
+    );
+    /// Apply the safety stage of the given **OutputOptions** to the output buffer from the
+    /// non-blocking C API stream parameters after the callback has written to it.
+    ///
+    /// Returns the number of samples that were altered.
+    fn finish_output(
+        output: *mut raw::c_void,
+        frame_count: raw::c_ulong,
+        out_channels: i32,
+        options: &OutputOptions,
+    ) -> SafetyStats;
 }
 
 /// **Streams** that can be read by the user.
@@ -75,7 +111,7 @@ pub trait Reader: Flow {
 /// **Streams** that can be written to by the user for output to some DAC.
 pub trait Writer: Flow {
     /// The sample format for the writable buffer.
-    type Sample;
+    type Sample: Sample;
     /// Mutably borrow the the writable **Buffer**.
     fn writable_buffer(blocking: &mut Blocking<Self::Buffer>) -> &mut Buffer;
     /// The number of channels in the writable **Buffer**.
@@ -84,16 +120,19 @@ pub trait Writer: Flow {
 
 /// An alias for the boxed Callback function type.
 type CallbackFn = dyn FnMut(
-    *const raw::c_void,
-    *mut raw::c_void,
-    raw::c_ulong,
-    *const ffi::PaStreamCallbackTimeInfo,
-    ffi::PaStreamCallbackFlags,
-) -> ffi::PaStreamCallbackResult;
+        *const raw::c_void,
+        *mut raw::c_void,
+        raw::c_ulong,
+        *const ffi::PaStreamCallbackTimeInfo,
+        ffi::PaStreamCallbackFlags,
+    ) -> ffi::PaStreamCallbackResult
+    + Send;
 
 /// A wrapper around a user-given **CallbackFn** that can be sent to PortAudio.
 struct CallbackFnWrapper {
     f: Box<CallbackFn>,
+    /// Run on the callback thread before the first call to `f`, then discarded.
+    init: Option<CallbackThreadInit>,
 }
 
 /// Timing information for the buffer passed to the input stream callback.
@@ -191,6 +230,34 @@ pub struct NonBlocking {
     callback: Box<CallbackFnWrapper>,
 }
 
+/// Initialisation run once on PortAudio's callback thread before the first call to a
+/// **NonBlocking** **Stream**'s callback.
+///
+/// PortAudio owns the callback thread, so this is the only opportunity to configure it. Steps run
+/// in the order in which they were added.
+///
+/// The presets are best-effort: they do nothing on platforms that don't support them and any
+/// failure is ignored. If a stream is stopped and restarted, some host APIs resume it on a new
+/// thread which will not have been initialised.
+///
+/// ```no_run
+/// extern crate portaudio as pa;
+/// # fn main() {
+/// let pa = pa::PortAudio::new().unwrap();
+/// let settings = pa.default_output_stream_settings::<f32>(2, 44_100.0, 256).unwrap();
+/// let init = pa::CallbackThreadInit::new()
+///     .flush_denormals()
+///     .thread_name("synth")
+///     .cpu_affinity(&[2]);
+/// let callback = |_args: pa::OutputStreamCallbackArgs<f32>| pa::Continue;
+/// let _stream = pa.open_non_blocking_stream_with_init(settings, init, callback);
+/// # }
+/// ```
+#[derive(Default)]
+pub struct CallbackThreadInit {
+    steps: Vec<Box<dyn FnOnce() + Send>>,
+}
+
 /// A type-safe PortAudio PaStream wrapper.
 ///
 /// **F** is the stream's directional [**Flow**][1]:
@@ -210,23 +277,28 @@ pub struct NonBlocking {
 /// **M** is the stream's [**Mode**][5]:
 ///
 /// - [**Blocking**][6] - The stream will be run on the caller's thread. For [**Blocking**][6]
-/// streams, a user can read from [**Input**][2] and [**Duplex**][4] streams using the
-/// [**Stream::read_available**][8] and [**Stream::read**][9] methods and write to [**Output**][3]
-/// and [**Duplex**][4] streams using the [**Stream::write_available**][10] and
-/// [**Stream::write**][11] methods. A [**Blocking**][6] **Stream** can be opened using the
-/// [**PortAudio::open_blocking_stream][12]** method.
+///   streams, a user can read from [**Input**][2] and [**Duplex**][4] streams using the
+///   [**Stream::read_available**][8] and [**Stream::read**][9] methods and write to
+///   [**Output**][3] and [**Duplex**][4] streams using the [**Stream::write_available**][10] and
+///   [**Stream::write**][11] methods. A [**Blocking**][6] **Stream** can be opened using the
+///   [**PortAudio::open_blocking_stream][12]** method.
 /// - [**NonBlocking**][7] - The stream will be run on a separate thread. [**NonBlocking][7]
-/// streams are read and written to via the callback arguments that are associated with the
-/// **Stream**'s [**Flow**][1] type:
+///   streams are read and written to via the callback arguments that are associated with the
+///   **Stream**'s [**Flow**][1] type:
 ///     - **Input** -> [**InputCallbackArgs**](./struct.InputCallbackArgs.html)
 ///     - **Output** -> [**OutputCallbackArgs**](./struct.OutputCallbackArgs.html)
 ///     - **Duplex** -> [**DuplexCallbackArgs**](./struct.DuplexCallbackArgs.html)
-/// A [**NonBlocking**][7] **Stream** can be opened using the
-/// [**PortAudio::open_non_blocking_stream][13]** method.
+///
+///   A [**NonBlocking**][7] **Stream** can be opened using the
+///   [**PortAudio::open_non_blocking_stream][13]** method.
 ///
 /// A **Stream** may only live as long as the **PortAudio** instance from which it was spawned and
 /// no longer.
 ///
+/// Dropping a **Stream** stops it, waiting for any pending buffers to play as **Stream::stop**
+/// does, and then closes it. Call **Stream::abort** beforehand to discard the pending buffers
+/// instead.
+///
 /// The original PortAudio documentation for the **PaStream** type can be found [here][17].
 ///
 /// [1]: ./trait.Flow.html
@@ -252,10 +324,11 @@ pub struct Stream<M, F> {
     mode: M,
     flow: F,
     port_audio_life: std::sync::Arc<super::Life>,
+    safety_counters: std::sync::Arc<SafetyCounters>,
 }
 
 /// Parameters for one direction (input or output) of a stream.
-#[derive(Copy, Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct Parameters<S> {
     /// Index of the device to be used, or a variant indicating to use the host-specific API.
     pub device: DeviceKind,
@@ -268,14 +341,96 @@ pub struct Parameters<S> {
     /// If `true`, audio data is passed as a single buffer with all channels interleaved.
     ///
     /// If `false`, audio data is passed as an array of pointers to separate buffers, one buffer
-    /// for each channel.
+    /// for each channel. **Stream**s only expose interleaved buffers, so opening a stream with
+    /// non-interleaved parameters fails with `ErrorKind::SampleFormatNotSupported`.
     pub is_interleaved: bool,
+    /// Host API specific stream information, e.g. an **ext::alsa::AlsaStreamInfo** naming the
+    /// PCM to open when the `device` is `UseHostApiSpecificDeviceSpecification`. `None` by
+    /// default.
+    ///
+    /// It is owned by the **Stream** opened with these parameters, and so remains valid for as
+    /// long as PortAudio may read it.
+    pub host_api_specific_stream_info: Option<Box<dyn HostApiSpecificStreamInfo>>,
     /// Sample format of the audio data provided to/by the device.
     sample_format: std::marker::PhantomData<S>,
 }
 
+/// Host API specific information about one direction of a stream, passed to PortAudio as the
+/// `hostApiSpecificStreamInfo` of its `PaStreamParameters`.
+///
+/// Implemented by the stream info types of the **ext** modules. An implementation must also be
+/// **Clone** and **PartialEq** so that the **Parameters** holding it may be.
+///
+/// # Safety
+///
+/// **as_ptr** must point to the structure that the host API of **host_api_type** expects, which
+/// must remain valid and unchanged for as long as `self` is alive, even if `self` is moved.
+pub unsafe trait HostApiSpecificStreamInfo:
+    HostApiSpecificStreamInfoPrivate + std::fmt::Debug + Send + Sync
+{
+    /// The host API whose devices the information applies to.
+    fn host_api_type(&self) -> HostApiTypeId;
+    /// A pointer to the host API specific structure.
+    fn as_ptr(&self) -> *const raw::c_void;
+}
+
+/// Options controlling how the output buffers of a **Stream** are prepared.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OutputOptions {
+    /// If `true`, output buffers are filled with silence before being passed to the non-blocking
+    /// stream callback or to the `write_fn` of **Stream::write**. `true` by default.
+    ///
+    /// PortAudio provides output buffers with uninitialised contents, so when this is `false` the
+    /// callback or `write_fn` must write every sample of the buffer before reading any of them.
+    pub zero_fill: bool,
+    /// An optional safety stage applied to output buffers after the non-blocking stream callback
+    /// or the `write_fn` of **Stream::write** has written to them. `None` by default.
+    ///
+    /// The number of samples altered by the stage may be retrieved via **Stream::safety_stats**.
+    pub safety: Option<SafetyOptions>,
+}
+
+/// A safety stage guarding the output device against malformed or excessively loud samples.
+///
+/// Samples are viewed in their normalised form, where full scale is `-1.0..=1.0`. NaN and
+/// infinite samples are replaced with silence and the remaining samples are passed through the
+/// **Limiter** at the given `ceiling`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SafetyOptions {
+    /// The kind of limiting applied to finite samples.
+    pub limiter: Limiter,
+    /// The greatest normalised magnitude that may be sent to the output device.
+    pub ceiling: f32,
+}
+
+/// The kinds of limiting that may be applied by a **SafetyOptions** stage.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Limiter {
+    /// Clamp samples whose magnitude exceeds the ceiling to the ceiling.
+    Hard,
+    /// Pass samples through unchanged up to a knee at three quarters of the ceiling and smoothly
+    /// compress samples above the knee so that they approach, but never exceed, the ceiling.
+    Soft,
+}
+
+/// The number of output samples altered by a **Stream**'s safety stage.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SafetyStats {
+    /// The number of NaN or infinite samples replaced with silence.
+    pub non_finite: u64,
+    /// The number of samples altered by the **Limiter**.
+    pub limited: u64,
+}
+
+/// The running totals behind a **Stream**'s **SafetyStats**, shared with the callback.
+#[derive(Debug, Default)]
+struct SafetyCounters {
+    non_finite: AtomicU64,
+    limited: AtomicU64,
+}
+
 /// Settings used to construct an **Input** **Stream**.
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct InputSettings<I> {
     /// The set of Parameters necessary for constructing the **Stream**.
     pub params: Parameters<I>,
@@ -288,7 +443,7 @@ pub struct InputSettings<I> {
 }
 
 /// Settings used to construct an **Out** **Stream**.
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct OutputSettings<O> {
     /// The set of Parameters necessary for constructing the **Stream**.
     pub params: Parameters<O>,
@@ -298,10 +453,12 @@ pub struct OutputSettings<O> {
     pub frames_per_buffer: u32,
     /// Any special **Stream** behaviour we require given as a set of flags.
     pub flags: Flags,
+    /// Options controlling how the output buffers are prepared.
+    pub output_options: OutputOptions,
 }
 
 /// Settings used to construct a **Duplex** **Stream**.
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct DuplexSettings<I, O> {
     /// The set of Parameters necessary for constructing the input **Stream**.
     pub in_params: Parameters<I>,
@@ -313,6 +470,8 @@ pub struct DuplexSettings<I, O> {
     pub frames_per_buffer: u32,
     /// Any special **Stream** behaviour we require given as a set of flags.
     pub flags: Flags,
+    /// Options controlling how the output buffers are prepared.
+    pub output_options: OutputOptions,
 }
 
 /// A type of **Flow** that describes an input-only **Stream**.
@@ -323,15 +482,16 @@ pub struct Input<I> {
 /// A type of **Flow** that describes an output-only **Stream**.
 pub struct Output<O> {
     params: Parameters<O>,
+    output_options: OutputOptions,
 }
 
 /// A type of **Flow** that describes a bi-directional (input *and* output) **Stream**.
 pub struct Duplex<I, O> {
     in_params: Parameters<I>,
     out_params: Parameters<O>,
+    output_options: OutputOptions,
 }
 
-unsafe impl Send for NonBlocking {}
 unsafe impl<M, F> Send for Stream<M, F>
 where
     M: Send,
@@ -356,9 +516,7 @@ impl<S> Parameters<S> {
     }
 
     /// The same as **Parameters::new**, but the device(s) to be used are specified in the host
-    /// api specific stream info structure.
-    ///
-    /// **NOTE:** This has not yet been tested.
+    /// api specific stream info structure, e.g. the `alsa_stream_info`.
     pub fn host_api_specific_device(
         channel_count: i32,
         is_interleaved: bool,
@@ -376,17 +534,145 @@ impl<S> Parameters<S> {
     ) -> Self {
         Parameters {
             device: device_kind,
-            channel_count: channel_count,
-            is_interleaved: is_interleaved,
-            suggested_latency: suggested_latency,
+            channel_count,
+            is_interleaved,
+            suggested_latency,
+            host_api_specific_stream_info: None,
             sample_format: std::marker::PhantomData,
         }
     }
+
+    /// Attach the given host API specific stream information.
+    pub fn with_host_api_specific_stream_info<T>(mut self, info: T) -> Self
+    where
+        T: HostApiSpecificStreamInfo + 'static,
+    {
+        self.host_api_specific_stream_info = Some(Box::new(info));
+        self
+    }
+
+    /// Check that the host API specific stream info, if any, applies to the host API of the
+    /// `device`.
+    ///
+    /// PortAudio reads the info as the structure of the device's host API, so passing the info of
+    /// another host API is undefined behaviour. Returns an
+    /// **IncompatibleHostApiSpecificStreamInfo** error with the given `context` if they differ.
+    pub fn check_host_api_specific_stream_info(&self, context: &'static str) -> Result<(), Error> {
+        let info = match self.host_api_specific_stream_info {
+            Some(ref info) => info,
+            None => return Ok(()),
+        };
+        // Without a device, the info itself determines the device.
+        let device = match self.device {
+            DeviceKind::Index(device) => device,
+            DeviceKind::UseHostApiSpecificDeviceSpecification => return Ok(()),
+        };
+        let _guard = super::lock();
+        let host_api_type = unsafe {
+            let device_info = ffi::Pa_GetDeviceInfo(device.into());
+            if device_info.is_null() {
+                return Err(Error::with_context(ErrorKind::InvalidDevice, context));
+            }
+            let host_api_info = ffi::Pa_GetHostApiInfo((*device_info).hostApi);
+            if host_api_info.is_null() {
+                return Err(Error::with_context(ErrorKind::InvalidHostApi, context));
+            }
+            HostApiTypeId::from((*host_api_info).type_)
+        };
+        if host_api_type != info.host_api_type() {
+            let kind = ErrorKind::IncompatibleHostApiSpecificStreamInfo;
+            return Err(Error::with_context(kind, context));
+        }
+        Ok(())
+    }
+
+    /// The `hostApiSpecificStreamInfo` to pass to PortAudio, or null if there is none.
+    fn c_host_api_specific_stream_info(&self) -> *mut raw::c_void {
+        match self.host_api_specific_stream_info {
+            Some(ref info) => info.as_ptr() as *mut raw::c_void,
+            None => ptr::null_mut(),
+        }
+    }
+}
+
+impl Clone for Box<dyn HostApiSpecificStreamInfo> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+impl PartialEq for dyn HostApiSpecificStreamInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_any(other.as_any())
+    }
+}
+
+impl Default for OutputOptions {
+    fn default() -> Self {
+        OutputOptions {
+            zero_fill: true,
+            safety: None,
+        }
+    }
+}
+
+impl SafetyOptions {
+    /// The fraction of the ceiling above which the **Limiter::Soft** begins compressing.
+    const SOFT_KNEE: f32 = 0.75;
+
+    /// Construct a safety stage with the given `limiter` and normalised `ceiling`.
+    pub fn new(limiter: Limiter, ceiling: f32) -> Self {
+        SafetyOptions { limiter, ceiling }
+    }
+
+    /// Limit the given finite normalised sample, returning `None` if it is left unchanged.
+    fn limit(&self, value: f32) -> Option<f32> {
+        let ceiling = self.ceiling.abs();
+        let magnitude = value.abs();
+        match self.limiter {
+            Limiter::Hard if magnitude > ceiling => Some(ceiling.copysign(value)),
+            Limiter::Soft if magnitude > ceiling * Self::SOFT_KNEE => {
+                let knee = ceiling * Self::SOFT_KNEE;
+                let range = ceiling - knee;
+                let compressed = knee + range * ((magnitude - knee) / range).tanh();
+                Some(compressed.copysign(value))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Default for SafetyOptions {
+    /// A hard limiter at full scale.
+    fn default() -> Self {
+        SafetyOptions::new(Limiter::Hard, 1.0)
+    }
+}
+
+impl SafetyCounters {
+    /// Add the given stats to the running totals.
+    fn add(&self, stats: SafetyStats) {
+        if stats.non_finite > 0 {
+            self.non_finite
+                .fetch_add(stats.non_finite, Ordering::Relaxed);
+        }
+        if stats.limited > 0 {
+            self.limited.fetch_add(stats.limited, Ordering::Relaxed);
+        }
+    }
+
+    /// A snapshot of the running totals.
+    fn stats(&self) -> SafetyStats {
+        SafetyStats {
+            non_finite: self.non_finite.load(Ordering::Relaxed),
+            limited: self.limited.load(Ordering::Relaxed),
+        }
+    }
 }
 
 /// Simplify implementation of one-way-Stream Settings types.
 macro_rules! impl_half_duplex_settings {
-    ($name:ident) => {
+    ($name:ident $(, $field:ident: $value:expr)*) => {
         impl<S> $name<S> {
             /// Construct the settings from the given `params`, `sample_rate` and
             /// `frames_per_buffer` with an empty set of **StreamFlags**.
@@ -403,22 +689,23 @@ macro_rules! impl_half_duplex_settings {
                 flags: Flags,
             ) -> Self {
                 $name {
-                    params: params,
-                    sample_rate: sample_rate,
-                    frames_per_buffer: frames_per_buffer,
-                    flags: flags,
+                    params,
+                    sample_rate,
+                    frames_per_buffer,
+                    flags,
+                    $($field: $value,)*
                 }
             }
         }
     };
 }
 
-impl_half_duplex_settings!(OutputSettings);
+impl_half_duplex_settings!(OutputSettings, output_options: OutputOptions::default());
 impl_half_duplex_settings!(InputSettings);
 
 impl<I, O> DuplexSettings<I, O> {
     /// Construct the settings from the given `params`, `sample_rate` and
-    /// `frames_per_buffer` with an empty set of **StreamFlags**.
+    /// `frames_per_buffer` with an empty set of **StreamFlags** and default options.
     pub fn new(
         in_params: Parameters<I>,
         out_params: Parameters<O>,
@@ -444,11 +731,12 @@ impl<I, O> DuplexSettings<I, O> {
         flags: Flags,
     ) -> Self {
         DuplexSettings {
-            in_params: in_params,
-            out_params: out_params,
-            sample_rate: sample_rate,
-            frames_per_buffer: frames_per_buffer,
-            flags: flags,
+            in_params,
+            out_params,
+            sample_rate,
+            frames_per_buffer,
+            flags,
+            output_options: OutputOptions::default(),
         }
     }
 }
@@ -458,7 +746,7 @@ where
     I: Sample + 'static,
 {
     type Buffer = Buffer;
-    type CallbackArgs = InputCallbackArgs<'static, I>;
+    type CallbackArgs<'a> = InputCallbackArgs<'a, I>;
     type CallbackTimeInfo = InputCallbackTimeInfo;
 
     fn new_buffer(&self, frames_per_buffer: u32) -> Self::Buffer {
@@ -472,10 +760,14 @@ where
         Option<ffi::PaStreamParameters>,
         Option<ffi::PaStreamParameters>,
     ) {
-        (Some(self.params.into()), None)
+        (Some((&self.params).into()), None)
+    }
+
+    fn check_host_api_specific_stream_info(&self, context: &'static str) -> Result<(), Error> {
+        self.params.check_host_api_specific_stream_info(context)
     }
 
-    fn new_callback_args(
+    unsafe fn new_callback_args<'a>(
         input: *const raw::c_void,
         _output: *mut raw::c_void,
         frame_count: raw::c_ulong,
@@ -483,8 +775,8 @@ where
         flags: ffi::PaStreamCallbackFlags,
         in_channels: i32,
         _out_channels: i32,
-    ) -> Self::CallbackArgs {
-        let flags = CallbackFlags::from_bits(flags).unwrap_or_else(|| CallbackFlags::empty());
+    ) -> Self::CallbackArgs<'a> {
+        let flags = CallbackFlags::from_bits(flags).unwrap_or_else(CallbackFlags::empty);
         let time = unsafe {
             InputCallbackTimeInfo {
                 current: (*time_info).currentTime,
@@ -500,12 +792,33 @@ where
             unsafe { std::slice::from_raw_parts(buffer_ptr, buffer_len) }
         };
         InputCallbackArgs {
-            buffer: buffer,
+            buffer,
             frames: frame_count as usize,
-            flags: flags,
-            time: time,
+            flags,
+            time,
         }
     }
+
+    fn output_options(&self) -> Option<OutputOptions> {
+        None
+    }
+
+    fn prepare_output(
+        _output: *mut raw::c_void,
+        _frame_count: raw::c_ulong,
+        _out_channels: i32,
+        _options: &OutputOptions,
+    ) {
+    }
+
+    fn finish_output(
+        _output: *mut raw::c_void,
+        _frame_count: raw::c_ulong,
+        _out_channels: i32,
+        _options: &OutputOptions,
+    ) -> SafetyStats {
+        SafetyStats::default()
+    }
 }
 
 impl<O> Flow for Output<O>
@@ -513,7 +826,7 @@ where
     O: Sample + 'static,
 {
     type Buffer = Buffer;
-    type CallbackArgs = OutputCallbackArgs<'static, O>;
+    type CallbackArgs<'a> = OutputCallbackArgs<'a, O>;
     type CallbackTimeInfo = OutputCallbackTimeInfo;
 
     fn params_both_directions(
@@ -522,7 +835,11 @@ where
         Option<ffi::PaStreamParameters>,
         Option<ffi::PaStreamParameters>,
     ) {
-        (None, Some(self.params.into()))
+        (None, Some((&self.params).into()))
+    }
+
+    fn check_host_api_specific_stream_info(&self, context: &'static str) -> Result<(), Error> {
+        self.params.check_host_api_specific_stream_info(context)
     }
 
     fn new_buffer(&self, frames_per_buffer: u32) -> Self::Buffer {
@@ -530,7 +847,7 @@ where
         Buffer::new::<O>(frames_per_buffer, channel_count)
     }
 
-    fn new_callback_args(
+    unsafe fn new_callback_args<'a>(
         _input: *const raw::c_void,
         output: *mut raw::c_void,
         frame_count: raw::c_ulong,
@@ -538,8 +855,8 @@ where
         flags: ffi::PaStreamCallbackFlags,
         _in_channels: i32,
         out_channels: i32,
-    ) -> Self::CallbackArgs {
-        let flags = CallbackFlags::from_bits(flags).unwrap_or_else(|| CallbackFlags::empty());
+    ) -> Self::CallbackArgs<'a> {
+        let flags = CallbackFlags::from_bits(flags).unwrap_or_else(CallbackFlags::empty);
         let time = unsafe {
             OutputCallbackTimeInfo {
                 current: (*time_info).currentTime,
@@ -555,12 +872,36 @@ where
             unsafe { std::slice::from_raw_parts_mut(buffer_ptr, buffer_len) }
         };
         OutputCallbackArgs {
-            buffer: buffer,
+            buffer,
             frames: frame_count as usize,
-            flags: flags,
-            time: time,
+            flags,
+            time,
         }
     }
+
+    fn output_options(&self) -> Option<OutputOptions> {
+        Some(self.output_options)
+    }
+
+    fn prepare_output(
+        output: *mut raw::c_void,
+        frame_count: raw::c_ulong,
+        out_channels: i32,
+        options: &OutputOptions,
+    ) {
+        let len = out_channels as usize * frame_count as usize;
+        prepare_output::<O>(output, len, options);
+    }
+
+    fn finish_output(
+        output: *mut raw::c_void,
+        frame_count: raw::c_ulong,
+        out_channels: i32,
+        options: &OutputOptions,
+    ) -> SafetyStats {
+        let len = out_channels as usize * frame_count as usize;
+        finish_output::<O>(output, len, options)
+    }
 }
 
 impl<I, O> Flow for Duplex<I, O>
@@ -569,7 +910,7 @@ where
     O: Sample + 'static,
 {
     type Buffer = (Buffer, Buffer);
-    type CallbackArgs = DuplexCallbackArgs<'static, I, O>;
+    type CallbackArgs<'a> = DuplexCallbackArgs<'a, I, O>;
     type CallbackTimeInfo = DuplexCallbackTimeInfo;
 
     fn params_both_directions(
@@ -578,7 +919,16 @@ where
         Option<ffi::PaStreamParameters>,
         Option<ffi::PaStreamParameters>,
     ) {
-        (Some(self.in_params.into()), Some(self.out_params.into()))
+        (
+            Some((&self.in_params).into()),
+            Some((&self.out_params).into()),
+        )
+    }
+
+    fn check_host_api_specific_stream_info(&self, context: &'static str) -> Result<(), Error> {
+        self.in_params
+            .check_host_api_specific_stream_info(context)?;
+        self.out_params.check_host_api_specific_stream_info(context)
     }
 
     fn new_buffer(&self, frames_per_buffer: u32) -> Self::Buffer {
@@ -589,7 +939,7 @@ where
         (in_buffer, out_buffer)
     }
 
-    fn new_callback_args(
+    unsafe fn new_callback_args<'a>(
         input: *const raw::c_void,
         output: *mut raw::c_void,
         frame_count: raw::c_ulong,
@@ -597,8 +947,8 @@ where
         flags: ffi::PaStreamCallbackFlags,
         in_channels: i32,
         out_channels: i32,
-    ) -> Self::CallbackArgs {
-        let flags = CallbackFlags::from_bits(flags).unwrap_or_else(|| CallbackFlags::empty());
+    ) -> Self::CallbackArgs<'a> {
+        let flags = CallbackFlags::from_bits(flags).unwrap_or_else(CallbackFlags::empty);
         let time = unsafe {
             DuplexCallbackTimeInfo {
                 current: (*time_info).currentTime,
@@ -620,13 +970,228 @@ where
             unsafe { std::slice::from_raw_parts_mut(buffer_ptr, buffer_len) }
         };
         DuplexCallbackArgs {
-            in_buffer: in_buffer,
-            out_buffer: out_buffer,
+            in_buffer,
+            out_buffer,
             frames: frame_count as usize,
-            flags: flags,
-            time: time,
+            flags,
+            time,
+        }
+    }
+
+    fn output_options(&self) -> Option<OutputOptions> {
+        Some(self.output_options)
+    }
+
+    fn prepare_output(
+        output: *mut raw::c_void,
+        frame_count: raw::c_ulong,
+        out_channels: i32,
+        options: &OutputOptions,
+    ) {
+        let len = out_channels as usize * frame_count as usize;
+        prepare_output::<O>(output, len, options);
+    }
+
+    fn finish_output(
+        output: *mut raw::c_void,
+        frame_count: raw::c_ulong,
+        out_channels: i32,
+        options: &OutputOptions,
+    ) -> SafetyStats {
+        let len = out_channels as usize * frame_count as usize;
+        finish_output::<O>(output, len, options)
+    }
+}
+
+impl CallbackThreadInit {
+    /// An initialisation with no steps.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the given closure as a step.
+    pub fn with<F>(mut self, step: F) -> Self
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.steps.push(Box::new(step));
+        self
+    }
+
+    /// Flush denormal floats to zero (FTZ) and treat denormal inputs as zero (DAZ).
+    ///
+    /// Denormal arithmetic is slow enough on x86 to cause dropouts in decaying filters and
+    /// reverbs. Does nothing on other architectures.
+    pub fn flush_denormals(self) -> Self {
+        self.with(callback_thread::flush_denormals)
+    }
+
+    /// Name the callback thread, making it identifiable in debuggers and profilers.
+    ///
+    /// The name is truncated to 15 bytes on Linux and at the first NUL byte. Only supported on
+    /// Linux, Android, macOS and iOS.
+    pub fn thread_name(self, name: &str) -> Self {
+        let name = name.to_string();
+        self.with(move || callback_thread::set_name(&name))
+    }
+
+    /// Restrict the callback thread to the CPUs with the given indices.
+    ///
+    /// Only supported on Linux and Android.
+    pub fn cpu_affinity(self, cpus: &[usize]) -> Self {
+        let cpus = cpus.to_vec();
+        self.with(move || callback_thread::set_affinity(&cpus))
+    }
+
+    /// Run and consume each of the steps in order.
+    fn run(self) {
+        for step in self.steps {
+            step();
+        }
+    }
+}
+
+impl std::fmt::Debug for CallbackThreadInit {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CallbackThreadInit")
+            .field("steps", &self.steps.len())
+            .finish()
+    }
+}
+
+/// The platform-specific implementations of the **CallbackThreadInit** presets.
+mod callback_thread {
+    /// Set the FTZ and DAZ bits of the MXCSR register.
+    #[cfg(any(
+        target_arch = "x86_64",
+        all(target_arch = "x86", target_feature = "sse")
+    ))]
+    #[allow(deprecated)]
+    pub fn flush_denormals() {
+        #[cfg(target_arch = "x86")]
+        use std::arch::x86::{_mm_getcsr, _mm_setcsr};
+        #[cfg(target_arch = "x86_64")]
+        use std::arch::x86_64::{_mm_getcsr, _mm_setcsr};
+        const FTZ: u32 = 1 << 15;
+        const DAZ: u32 = 1 << 6;
+        unsafe { _mm_setcsr(_mm_getcsr() | FTZ | DAZ) };
+    }
+
+    #[cfg(not(any(
+        target_arch = "x86_64",
+        all(target_arch = "x86", target_feature = "sse")
+    )))]
+    pub fn flush_denormals() {}
+
+    /// The name up to its first NUL byte as a C string.
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios"
+    ))]
+    fn c_name(name: &str, max_len: usize) -> std::ffi::CString {
+        let mut bytes: Vec<u8> = name.bytes().take_while(|&b| b != 0).collect();
+        // Don't split a multi-byte character when truncating.
+        let mut len = bytes.len().min(max_len);
+        while !name.is_char_boundary(len) {
+            len -= 1;
+        }
+        bytes.truncate(len);
+        std::ffi::CString::new(bytes).expect("NUL bytes were removed")
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn set_name(name: &str) {
+        // Linux limits thread names to 16 bytes including the NUL terminator.
+        let name = c_name(name, 15);
+        unsafe { libc::pthread_setname_np(libc::pthread_self(), name.as_ptr()) };
+    }
+
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    pub fn set_name(name: &str) {
+        let name = c_name(name, 63);
+        unsafe { libc::pthread_setname_np(name.as_ptr()) };
+    }
+
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios"
+    )))]
+    pub fn set_name(_name: &str) {}
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn set_affinity(cpus: &[usize]) {
+        unsafe {
+            let mut set: libc::cpu_set_t = std::mem::zeroed();
+            libc::CPU_ZERO(&mut set);
+            for &cpu in cpus {
+                if cpu < libc::CPU_SETSIZE as usize {
+                    libc::CPU_SET(cpu, &mut set);
+                }
+            }
+            // A pid of 0 refers to the calling thread.
+            libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set);
+        }
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    pub fn set_affinity(_cpus: &[usize]) {}
+}
+
+/// Prepare the `len` samples of the output buffer at `output` according to the `options`.
+fn prepare_output<S>(output: *mut raw::c_void, len: usize, options: &OutputOptions)
+where
+    S: Sample,
+{
+    let samples = unsafe { std::slice::from_raw_parts_mut(output as *mut S, len) };
+    prepare_samples(samples, options)
+}
+
+/// Apply the safety stage of the `options` to the `len` samples of the output buffer at `output`.
+fn finish_output<S>(output: *mut raw::c_void, len: usize, options: &OutputOptions) -> SafetyStats
+where
+    S: Sample,
+{
+    let samples = unsafe { std::slice::from_raw_parts_mut(output as *mut S, len) };
+    finish_samples(samples, options)
+}
+
+/// Prepare the output `samples` according to the `options`.
+fn prepare_samples<S>(samples: &mut [S], options: &OutputOptions)
+where
+    S: Sample,
+{
+    if options.zero_fill {
+        for sample in samples.iter_mut() {
+            *sample = S::silence();
+        }
+    }
+}
+
+/// Apply the safety stage of the `options` to the output `samples`.
+fn finish_samples<S>(samples: &mut [S], options: &OutputOptions) -> SafetyStats
+where
+    S: Sample,
+{
+    let mut stats = SafetyStats::default();
+    let safety = match options.safety {
+        Some(ref safety) => safety,
+        None => return stats,
+    };
+    for sample in samples.iter_mut() {
+        let value = sample.to_normalised();
+        if !value.is_finite() {
+            *sample = S::silence();
+            stats.non_finite += 1;
+        } else if let Some(limited) = safety.limit(value) {
+            *sample = S::from_normalised(limited);
+            stats.limited += 1;
         }
     }
+    stats
 }
 
 impl<I> Reader for Input<I>
@@ -686,6 +1251,8 @@ where
 /// The buffer used to transfer audio data between the input and output streams.
 pub struct Buffer {
     data: *mut libc::c_void,
+    /// The number of frames the buffer was allocated for.
+    frames: u32,
 }
 
 pub mod flags {
@@ -857,29 +1424,33 @@ impl<S: Sample> Parameters<S> {
         }
         let device = match c_params.device {
             n if n >= 0 => DeviceIndex(n as u32).into(),
-            -1 => DeviceKind::UseHostApiSpecificDeviceSpecification,
+            -2 => DeviceKind::UseHostApiSpecificDeviceSpecification,
             _ => return None,
         };
         Some(Parameters {
-            device: device,
+            device,
             channel_count: c_params.channelCount,
             suggested_latency: c_params.suggestedLatency,
-            is_interleaved: is_interleaved,
+            is_interleaved,
+            host_api_specific_stream_info: None,
             sample_format: std::marker::PhantomData,
         })
     }
 }
 
-impl<S: Sample> From<Parameters<S>> for ffi::PaStreamParameters {
+impl<'a, S: Sample> From<&'a Parameters<S>> for ffi::PaStreamParameters {
     /// Converts the **Parameters** into its matching `C_PaStreamParameters`.
-    fn from(params: Parameters<S>) -> Self {
+    ///
+    /// The `hostApiSpecificStreamInfo` points into the **Parameters**, so the result must not be
+    /// used after they are dropped.
+    fn from(params: &'a Parameters<S>) -> Self {
         let Parameters {
             device,
             channel_count,
             suggested_latency,
             is_interleaved,
             ..
-        } = params;
+        } = *params;
         let sample_format = S::sample_format();
         let mut sample_format_flags = sample_format.flags();
         if !is_interleaved {
@@ -890,7 +1461,7 @@ impl<S: Sample> From<Parameters<S>> for ffi::PaStreamParameters {
             channelCount: channel_count as raw::c_int,
             sampleFormat: sample_format_flags.bits(),
             suggestedLatency: suggested_latency,
-            hostApiSpecificStreamInfo: ptr::null_mut(),
+            hostApiSpecificStreamInfo: params.c_host_api_specific_stream_info(),
         }
     }
 }
@@ -904,7 +1475,7 @@ impl<I> Settings for InputSettings<I> {
             frames_per_buffer,
             flags,
         } = self;
-        let flow = Input { params: params };
+        let flow = Input { params };
         (flow, sample_rate, frames_per_buffer, flags)
     }
 }
@@ -917,8 +1488,12 @@ impl<O> Settings for OutputSettings<O> {
             sample_rate,
             frames_per_buffer,
             flags,
+            output_options,
         } = self;
-        let flow = Output { params: params };
+        let flow = Output {
+            params,
+            output_options,
+        };
         (flow, sample_rate, frames_per_buffer, flags)
     }
 }
@@ -932,10 +1507,12 @@ impl<I, O> Settings for DuplexSettings<I, O> {
             sample_rate,
             frames_per_buffer,
             flags,
+            output_options,
         } = self;
         let flow = Duplex {
-            in_params: in_params,
-            out_params: out_params,
+            in_params,
+            out_params,
+            output_options,
         };
         (flow, sample_rate, frames_per_buffer, flags)
     }
@@ -949,12 +1526,22 @@ impl Buffer {
         let n_channels = channel_count as libc::size_t;
         let malloc_size = sample_format_bytes * n_frames * n_channels;
         Buffer {
-            data: unsafe { libc::malloc(malloc_size) as *mut libc::c_void },
+            data: unsafe { libc::malloc(malloc_size) },
+            frames: frames_per_buffer,
         }
     }
 
+    /// Check that the given number of `frames` fits within the **Buffer**, returning an
+    /// `ErrorKind::BufferTooBig` error with the given `context` if it doesn't.
+    fn check_frames(&self, frames: u32, context: &'static str) -> Result<(), Error> {
+        if frames > self.frames {
+            return Err(Error::with_context(ErrorKind::BufferTooBig, context));
+        }
+        Ok(())
+    }
+
     /// Convert the **Buffer**'s data field into a slice with the given format.
-    unsafe fn slice<'a, S>(&'a self, frames: u32, channels: i32) -> &'a [S] {
+    unsafe fn slice<S>(&self, frames: u32, channels: i32) -> &[S] {
         let len = (frames * channels as u32) as usize;
         // TODO: At the moment, we assume this buffer is interleaved. We need to check whether
         // or not buffer is interleaved here. This should probably an extra type parameter
@@ -963,7 +1550,7 @@ impl Buffer {
     }
 
     /// Convert the **Buffer**'s data field into a mutable slice with the given format.
-    unsafe fn slice_mut<'a, S>(&'a mut self, frames: u32, channels: i32) -> &'a mut [S] {
+    unsafe fn slice_mut<S>(&mut self, frames: u32, channels: i32) -> &mut [S] {
         let len = (frames * channels as u32) as usize;
         // TODO: At the moment, we assume this buffer is interleaved. We need to check whether
         // or not buffer is interleaved here. This should probably an extra type parameter
@@ -978,6 +1565,26 @@ impl Drop for Buffer {
     }
 }
 
+/// Check that the audio data of each direction is interleaved, returning an
+/// `ErrorKind::SampleFormatNotSupported` error with the given `context` if it isn't.
+///
+/// The buffers of both blocking and non-blocking **Stream**s are exposed as interleaved slices, so
+/// PortAudio's array of per-channel buffers can't be passed through.
+fn check_interleaved(
+    in_params: Option<ffi::PaStreamParameters>,
+    out_params: Option<ffi::PaStreamParameters>,
+    context: &'static str,
+) -> Result<(), Error> {
+    let non_interleaved = |params: Option<ffi::PaStreamParameters>| matches!(params, Some(p) if p.sampleFormat & ffi::PA_NON_INTERLEAVED != 0);
+    if non_interleaved(in_params) || non_interleaved(out_params) {
+        return Err(Error::with_context(
+            ErrorKind::SampleFormatNotSupported,
+            context,
+        ));
+    }
+    Ok(())
+}
+
 fn open_blocking_stream(
     in_params: Option<ffi::PaStreamParameters>,
     out_params: Option<ffi::PaStreamParameters>,
@@ -987,13 +1594,11 @@ fn open_blocking_stream(
 ) -> Result<*mut raw::c_void, Error> {
     // The pointer to which PortAudio will attach the stream.
     let mut c_stream_ptr: *mut raw::c_void = ptr::null_mut();
-    let in_c_params = in_params.map(|p| p.into());
-    let out_c_params = out_params.map(|p| p.into());
-    let in_c_params_ptr = in_c_params
+    let in_c_params_ptr = in_params
         .as_ref()
         .map(|p| p as *const _)
         .unwrap_or(ptr::null());
-    let out_c_params_ptr = out_c_params
+    let out_c_params_ptr = out_params
         .as_ref()
         .map(|p| p as *const _)
         .unwrap_or(ptr::null());
@@ -1001,6 +1606,7 @@ fn open_blocking_stream(
 
     // open the PortAudio stream.
     unsafe {
+        let _guard = super::lock();
         let error_code = ffi::Pa_OpenStream(
             &mut c_stream_ptr,
             in_c_params_ptr,
@@ -1011,11 +1617,7 @@ fn open_blocking_stream(
             None,
             ptr::null_mut(),
         );
-        let error = FromPrimitive::from_i32(error_code).unwrap();
-        match error {
-            Error::NoError => Ok(c_stream_ptr),
-            err => Err(err),
-        }
+        super::result_from_error_code(error_code, "Pa_OpenStream").map(|()| c_stream_ptr)
     }
 }
 
@@ -1029,13 +1631,11 @@ fn open_non_blocking_stream(
 ) -> Result<*mut raw::c_void, Error> {
     // The pointer to which PortAudio will attach the stream.
     let mut c_stream_ptr: *mut raw::c_void = ptr::null_mut();
-    let in_c_params = in_params.map(|p| p.into());
-    let out_c_params = out_params.map(|p| p.into());
-    let in_c_params_ptr = in_c_params
+    let in_c_params_ptr = in_params
         .as_ref()
         .map(|p| p as *const _)
         .unwrap_or(ptr::null());
-    let out_c_params_ptr = out_c_params
+    let out_c_params_ptr = out_params
         .as_ref()
         .map(|p| p as *const _)
         .unwrap_or(ptr::null());
@@ -1058,6 +1658,7 @@ fn open_non_blocking_stream(
 
     // open the PortAudio stream.
     unsafe {
+        let _guard = super::lock();
         let error_code = ffi::Pa_OpenStream(
             &mut c_stream_ptr,
             in_c_params_ptr,
@@ -1068,67 +1669,68 @@ fn open_non_blocking_stream(
             Some(stream_callback_proc),
             user_data,
         );
-        let error = FromPrimitive::from_i32(error_code).unwrap();
-        match error {
-            Error::NoError => Ok(c_stream_ptr),
-            err => Err(err),
-        }
+        super::result_from_error_code(error_code, "Pa_OpenStream").map(|()| c_stream_ptr)
     }
 }
 
 impl<M, F> Stream<M, F> {
-    fn new_unopened(mode: M, flow: F, life: std::sync::Arc<super::Life>) -> Self {
+    fn new_unopened(
+        mode: M,
+        flow: F,
+        life: std::sync::Arc<super::Life>,
+        safety_counters: std::sync::Arc<SafetyCounters>,
+    ) -> Self {
         Stream {
             pa_stream: ptr::null_mut(),
-            mode: mode,
-            flow: flow,
+            mode,
+            flow,
             port_audio_life: life,
+            safety_counters,
         }
     }
 
+    /// The number of output samples altered by the safety stage since the stream was opened.
+    ///
+    /// Always zero if the stream's **OutputOptions** have no safety stage.
+    ///
+    /// This does not acquire rust-portaudio's internal lock and so may be called from any thread.
+    pub fn safety_stats(&self) -> SafetyStats {
+        self.safety_counters.stats()
+    }
+
     /// Closes an audio stream.
     ///
     /// If the audio stream is active it discards any pending buffers as if Stream::abort had been
     /// called.
     pub fn close(&mut self) -> Result<(), Error> {
+        let _guard = super::lock();
         let error_code = unsafe { ffi::Pa_CloseStream(self.pa_stream) };
-        let error = FromPrimitive::from_i32(error_code).unwrap();
-        match error {
-            Error::NoError => Ok(()),
-            err => Err(err),
-        }
+        super::result_from_error_code(error_code, "Pa_CloseStream")
     }
 
     /// Commences audio processing.
     pub fn start(&mut self) -> Result<(), Error> {
+        let _guard = super::lock();
         let error_code = unsafe { ffi::Pa_StartStream(self.pa_stream) };
-        let error = FromPrimitive::from_i32(error_code).unwrap();
-        match error {
-            0 => Ok(()),
-            err => Err(FromPrimitive::from_i32(err).unwrap()),
-        }
+        super::result_from_error_code(error_code, "Pa_StartStream")
     }
 
     /// Terminates audio processing.
     ///
-    /// It waits until all pending audio buffers have been played before it returns.
+    /// It waits until all pending audio buffers have been played before it returns. Until then,
+    /// rust-portaudio's lock on the PortAudio API remains held and calls from other threads wait
+    /// (see the thread safety section of the **PortAudio** docs).
     pub fn stop(&mut self) -> Result<(), Error> {
+        let _guard = super::lock();
         let error_code = unsafe { ffi::Pa_StopStream(self.pa_stream) };
-        let error = FromPrimitive::from_i32(error_code).unwrap();
-        match error {
-            0 => Ok(()),
-            err => Err(FromPrimitive::from_i32(err).unwrap()),
-        }
+        super::result_from_error_code(error_code, "Pa_StopStream")
     }
 
     /// Terminates audio processing immediately without waiting for pending buffers to complete.
     pub fn abort(&mut self) -> Result<(), Error> {
+        let _guard = super::lock();
         let error_code = unsafe { ffi::Pa_AbortStream(self.pa_stream) };
-        let error = FromPrimitive::from_i32(error_code).unwrap();
-        match error {
-            0 => Ok(()),
-            err => Err(FromPrimitive::from_i32(err).unwrap()),
-        }
+        super::result_from_error_code(error_code, "Pa_AbortStream")
     }
 
     /// Determine whether the stream is stopped.
@@ -1147,11 +1749,12 @@ impl<M, F> Stream<M, F> {
     ///
     /// TODO: Clarify what errors can actually an occur.
     pub fn is_stopped(&self) -> Result<bool, Error> {
+        let _guard = super::lock();
         let error_code = unsafe { ffi::Pa_IsStreamStopped(self.pa_stream) };
         match error_code {
             1 => Ok(true),
             0 => Ok(false),
-            err => Err(FromPrimitive::from_i32(err).unwrap()),
+            err => Err(Error::from_code(err, "Pa_IsStreamStopped")),
         }
     }
 
@@ -1170,11 +1773,12 @@ impl<M, F> Stream<M, F> {
     ///
     /// TODO: Clarify what errors can actually an occur.
     pub fn is_active(&self) -> Result<bool, Error> {
+        let _guard = super::lock();
         let error_code = unsafe { ffi::Pa_IsStreamActive(self.pa_stream) };
         match error_code {
             0 => Ok(false),
             1 => Ok(true),
-            err => Err(FromPrimitive::from_i32(err).unwrap()),
+            err => Err(Error::from_code(err, "Pa_IsStreamActive")),
         }
     }
 
@@ -1190,12 +1794,16 @@ impl<M, F> Stream<M, F> {
     /// method.
     ///
     /// Returns the stream's current time in seconds, or 0 if an error occurred.
+    ///
+    /// Unlike most methods, this does not acquire rust-portaudio's internal lock and so may be
+    /// called from the stream callback (see the **PortAudio** thread safety docs).
     pub fn time(&self) -> Time {
         unsafe { ffi::Pa_GetStreamTime(self.pa_stream) }
     }
 
     /// Retrieve a Info structure containing information about the stream.
     pub fn info(&self) -> Info {
+        let _guard = super::lock();
         unsafe {
             let info = ffi::Pa_GetStreamInfo(self.pa_stream);
             Info::from(*info)
@@ -1219,10 +1827,13 @@ where
         S: Settings<Flow = F>,
     {
         let (flow, sample_rate, frames_per_buffer, flags) = settings.into_flow_and_settings();
-        let buffer = flow.new_buffer(frames_per_buffer);
-        let blocking = Blocking { buffer: buffer };
+        flow.check_host_api_specific_stream_info("Pa_OpenStream")?;
         let (in_params, out_params) = flow.params_both_directions();
-        let mut stream = Stream::new_unopened(blocking, flow, life);
+        check_interleaved(in_params, out_params, "Pa_OpenStream")?;
+        let buffer = flow.new_buffer(frames_per_buffer);
+        let blocking = Blocking { buffer };
+        let counters = std::sync::Arc::new(SafetyCounters::default());
+        let mut stream = Stream::new_unopened(blocking, flow, life, counters);
         open_blocking_stream(in_params, out_params, sample_rate, frames_per_buffer, flags).map(
             |pa_stream| {
                 stream.pa_stream = pa_stream;
@@ -1249,11 +1860,14 @@ where
     pub fn read_available(&self) -> Result<Available, Error> {
         match unsafe { ffi::Pa_GetStreamReadAvailable(self.pa_stream) } {
             n if n >= 0 => Ok(Available::Frames(n)),
-            n => match FromPrimitive::from_i64(n as i64) {
-                Some(Error::InputOverflowed) => Ok(Available::InputOverflowed),
-                Some(Error::OutputUnderflowed) => Ok(Available::OutputUnderflowed),
-                Some(err) => Err(err),
-                _ => panic!("Undefined error code: {:?}", n),
+            n => match Error::from_code(n as ffi::PaError, "Pa_GetStreamReadAvailable") {
+                ref err if err.kind() == ErrorKind::InputOverflowed => {
+                    Ok(Available::InputOverflowed)
+                }
+                ref err if err.kind() == ErrorKind::OutputUnderflowed => {
+                    Ok(Available::OutputUnderflowed)
+                }
+                err => Err(err),
             },
         }
     }
@@ -1268,11 +1882,13 @@ where
     ///
     /// Returns an interleaved slice containing the read audio data.
     ///
-    /// Returns an `Error` if some error occurred.
+    /// Returns an `ErrorKind::BufferTooBig` error if `frames` exceeds the `frames_per_buffer` the
+    /// stream was opened with, and an `Error` if some other error occurred.
     ///
     /// TODO: Research and document exactly what errors can occur.
-    pub fn read<'b>(&'b self, frames: u32) -> Result<&'b [F::Sample], Error> {
+    pub fn read(&self, frames: u32) -> Result<&[F::Sample], Error> {
         let buffer = F::readable_buffer(&self.mode);
+        buffer.check_frames(frames, "Pa_ReadStream")?;
         let err = unsafe {
             ffi::Pa_ReadStream(
                 self.pa_stream,
@@ -1285,7 +1901,7 @@ where
                 let channel_count = Reader::channel_count(&self.flow);
                 Ok(buffer.slice(frames, channel_count))
             },
-            err => Err(FromPrimitive::from_i32(err).unwrap()),
+            err => Err(Error::from_code(err, "Pa_ReadStream")),
         }
     }
 }
@@ -1307,11 +1923,14 @@ where
     pub fn write_available(&self) -> Result<Available, Error> {
         match unsafe { ffi::Pa_GetStreamWriteAvailable(self.pa_stream) } {
             n if n >= 0 => Ok(Available::Frames(n)),
-            n => match FromPrimitive::from_i64(n as i64) {
-                Some(Error::InputOverflowed) => Ok(Available::InputOverflowed),
-                Some(Error::OutputUnderflowed) => Ok(Available::OutputUnderflowed),
-                Some(err) => Err(err),
-                _ => panic!("Undefined error code: {:?}", n),
+            n => match Error::from_code(n as ffi::PaError, "Pa_GetStreamWriteAvailable") {
+                ref err if err.kind() == ErrorKind::InputOverflowed => {
+                    Ok(Available::InputOverflowed)
+                }
+                ref err if err.kind() == ErrorKind::OutputUnderflowed => {
+                    Ok(Available::OutputUnderflowed)
+                }
+                err => Err(err),
             },
         }
     }
@@ -1326,26 +1945,33 @@ where
     /// * frames - The number of frames in the buffer.
     /// * write_fn - The buffer contains samples in the format specified by S.
     ///
-    /// Returns Ok(()) on success and an Err(Error) variant on failure.
+    /// The buffer is filled with silence before `write_fn` is called and passed through the
+    /// safety stage afterwards as configured by the stream's **OutputOptions**.
+    ///
+    /// Returns Ok(()) on success and an Err(Error) variant on failure. The error is of
+    /// `ErrorKind::BufferTooBig` if `frames` exceeds the `frames_per_buffer` the stream was opened
+    /// with, in which case `write_fn` isn't called.
     pub fn write<WF>(&mut self, frames: u32, write_fn: WF) -> Result<(), Error>
     where
         WF: for<'b> FnOnce(&'b mut [F::Sample]),
     {
         let pa_stream = self.pa_stream;
         let channels = Writer::channel_count(&self.flow);
+        let output_options = self.flow.output_options().unwrap_or_default();
         let out_buffer = F::writable_buffer(&mut self.mode);
-        let written_slice = {
-            let slice = unsafe { out_buffer.slice_mut(frames, channels) };
-            write_fn(slice);
-            slice
-        };
+        out_buffer.check_frames(frames, "Pa_WriteStream")?;
+        let written_slice = unsafe { out_buffer.slice_mut(frames, channels) };
+        prepare_samples(written_slice, &output_options);
+        write_fn(written_slice);
+        let stats = finish_samples(written_slice, &output_options);
+        self.safety_counters.add(stats);
         let result = unsafe {
             let written_slice_ptr = written_slice.as_ptr() as *mut raw::c_void;
             ffi::Pa_WriteStream(pa_stream, written_slice_ptr, frames as raw::c_ulong)
         };
         match result {
             0 => Ok(()),
-            err => Err(FromPrimitive::from_i32(err).unwrap()),
+            err => Err(Error::from_code(err, "Pa_WriteStream")),
         }
     }
 }
@@ -1355,17 +1981,38 @@ impl<F> Stream<NonBlocking, F> {
     pub fn open<S, C>(
         life: std::sync::Arc<super::Life>,
         settings: S,
+        callback: C,
+    ) -> Result<Self, Error>
+    where
+        S: Settings<Flow = F>,
+        F: Flow,
+        C: for<'a> FnMut(F::CallbackArgs<'a>) -> ffi::PaStreamCallbackResult + Send + 'static,
+    {
+        Self::open_with_init(life, settings, CallbackThreadInit::new(), callback)
+    }
+
+    /// Open a new **NonBlocking** **Stream** with the given **Flow** and settings, running `init`
+    /// on the callback thread before the first call to the `callback`.
+    pub fn open_with_init<S, C>(
+        life: std::sync::Arc<super::Life>,
+        settings: S,
+        init: CallbackThreadInit,
         mut callback: C,
     ) -> Result<Self, Error>
     where
         S: Settings<Flow = F>,
         F: Flow,
-        C: FnMut(F::CallbackArgs) -> ffi::PaStreamCallbackResult + 'static,
+        C: for<'a> FnMut(F::CallbackArgs<'a>) -> ffi::PaStreamCallbackResult + Send + 'static,
     {
         let (flow, sample_rate, frames_per_buffer, flags) = settings.into_flow_and_settings();
+        flow.check_host_api_specific_stream_info("Pa_OpenStream")?;
         let (in_params, out_params) = flow.params_both_directions();
+        check_interleaved(in_params, out_params, "Pa_OpenStream")?;
         let in_channels = in_params.map(|p| p.channelCount).unwrap_or(0);
         let out_channels = out_params.map(|p| p.channelCount).unwrap_or(0);
+        let output_options = flow.output_options().unwrap_or_default();
+        let counters = std::sync::Arc::new(SafetyCounters::default());
+        let callback_counters = counters.clone();
 
         let callback_wrapper_fn = move |input: *const raw::c_void,
                                         output: *mut raw::c_void,
@@ -1373,16 +2020,22 @@ impl<F> Stream<NonBlocking, F> {
                                         time_info: *const ffi::PaStreamCallbackTimeInfo,
                                         flags: ffi::PaStreamCallbackFlags|
               -> ffi::PaStreamCallbackResult {
-            let args = F::new_callback_args(
-                input,
-                output,
-                frame_count,
-                time_info,
-                flags,
-                in_channels,
-                out_channels,
-            );
-            callback(args)
+            F::prepare_output(output, frame_count, out_channels, &output_options);
+            let args = unsafe {
+                F::new_callback_args(
+                    input,
+                    output,
+                    frame_count,
+                    time_info,
+                    flags,
+                    in_channels,
+                    out_channels,
+                )
+            };
+            let result = callback(args);
+            let stats = F::finish_output(output, frame_count, out_channels, &output_options);
+            callback_counters.add(stats);
+            result
         };
 
         let non_blocking = NonBlocking {
@@ -1394,10 +2047,11 @@ impl<F> Stream<NonBlocking, F> {
                 // Here we `Box` the callback fn as we can't handle generic types in the c callback
                 // function.
                 f: Box::new(callback_wrapper_fn),
+                init: Some(init),
             }),
         };
 
-        let mut stream = Stream::new_unopened(non_blocking, flow, life);
+        let mut stream = Stream::new_unopened(non_blocking, flow, life, counters);
         open_non_blocking_stream(
             in_params,
             out_params,
@@ -1416,6 +2070,9 @@ impl<F> Stream<NonBlocking, F> {
     ///
     /// The "CPU Load" is a fraction of total CPU time consumed by a callback stream's audio
     /// processing routines including, but not limited to the client supplied stream callback.
+    ///
+    /// This does not acquire rust-portaudio's internal lock and so may be called from the stream
+    /// callback.
     pub fn cpu_load(&self) -> f64 {
         unsafe { ffi::Pa_GetStreamCpuLoad(self.pa_stream) }
     }
@@ -1439,5 +2096,136 @@ extern "C" fn stream_callback_proc(
     user_callback_ptr: *mut raw::c_void,
 ) -> ffi::PaStreamCallbackResult {
     let callback = user_callback_ptr as *mut CallbackFnWrapper;
-    unsafe { ((*callback).f)(input, output, frame_count, time_info, flags) }
+    unsafe {
+        if let Some(init) = (*callback).init.take() {
+            init.run();
+        }
+        ((*callback).f)(input, output, frame_count, time_info, flags)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        finish_output, finish_samples, prepare_output, Limiter, OutputOptions, SafetyCounters,
+        SafetyOptions, SafetyStats,
+    };
+    use private::SamplePrivate;
+    use std::f32;
+    use std::os::raw;
+
+    fn options(limiter: Limiter, ceiling: f32) -> OutputOptions {
+        OutputOptions {
+            zero_fill: true,
+            safety: Some(SafetyOptions::new(limiter, ceiling)),
+        }
+    }
+
+    #[test]
+    fn hard_limiter_clamps_to_ceiling() {
+        let mut samples = [0.25f32, 0.5, -0.5, 0.75, -2.0];
+        let stats = finish_samples(&mut samples, &options(Limiter::Hard, 0.5));
+        assert_eq!(samples, [0.25, 0.5, -0.5, 0.5, -0.5]);
+        assert_eq!(stats.limited, 2);
+        assert_eq!(stats.non_finite, 0);
+    }
+
+    #[test]
+    fn soft_limiter_compresses_above_knee() {
+        let safety = SafetyOptions::new(Limiter::Soft, 1.0);
+        // Below the knee, samples pass through unchanged.
+        assert_eq!(safety.limit(0.5), None);
+        assert_eq!(safety.limit(-0.75), None);
+        let mut last = 0.75;
+        for &value in &[0.8f32, 1.0, 1.5] {
+            let limited = safety.limit(value).unwrap();
+            assert!(limited > last && limited < 1.0, "{} -> {}", value, limited);
+            assert_eq!(safety.limit(-value), Some(-limited));
+            last = limited;
+        }
+        assert!(safety.limit(f32::MAX).unwrap() <= 1.0);
+    }
+
+    #[test]
+    fn non_finite_samples_become_silence() {
+        let mut samples = [f32::NAN, f32::INFINITY, f32::NEG_INFINITY, 0.5];
+        let stats = finish_samples(&mut samples, &options(Limiter::Hard, 1.0));
+        assert_eq!(samples, [0.0, 0.0, 0.0, 0.5]);
+        assert_eq!(stats.non_finite, 3);
+        assert_eq!(stats.limited, 0);
+    }
+
+    #[test]
+    fn integer_samples_are_limited_in_normalised_form() {
+        let mut samples = [i16::MAX, i16::MIN, 100];
+        let stats = finish_samples(&mut samples, &options(Limiter::Hard, 0.5));
+        assert_eq!(samples, [16_384, -16_384, 100]);
+        assert_eq!(stats.limited, 2);
+
+        let mut samples = [0xffu8, 0x00, 0x80];
+        let stats = finish_samples(&mut samples, &options(Limiter::Hard, 0.5));
+        assert_eq!(samples, [0xc0, 0x40, 0x80]);
+        assert_eq!(stats.limited, 2);
+    }
+
+    #[test]
+    fn normalisation_round_trips() {
+        // An `f32` only holds 24 significant bits, so not every `i32` survives the round trip.
+        for &sample in &[i32::MIN, -256, 0, 256, 1 << 30] {
+            assert_eq!(i32::from_normalised(sample.to_normalised()), sample);
+        }
+        for sample in i16::MIN..=i16::MAX {
+            assert_eq!(i16::from_normalised(sample.to_normalised()), sample);
+        }
+        for sample in i8::MIN..=i8::MAX {
+            assert_eq!(i8::from_normalised(sample.to_normalised()), sample);
+        }
+        for sample in u8::MIN..=u8::MAX {
+            assert_eq!(u8::from_normalised(sample.to_normalised()), sample);
+        }
+        // Out of range values saturate at the bounds of the integer type.
+        assert_eq!(i16::from_normalised(2.0), i16::MAX);
+        assert_eq!(i8::from_normalised(-2.0), i8::MIN);
+        assert_eq!(u8::from_normalised(2.0), u8::MAX);
+    }
+
+    #[test]
+    fn u8_silence_is_centred() {
+        assert_eq!(u8::silence(), 0x80);
+        assert_eq!(u8::silence().to_normalised(), 0.0);
+        assert_eq!(u8::from_normalised(0.0), 0x80);
+        let mut samples = [0xffu8; 4];
+        let mut samples_ptr = samples.as_mut_ptr() as *mut raw::c_void;
+        prepare_output::<u8>(samples_ptr, 4, &OutputOptions::default());
+        assert_eq!(samples, [0x80; 4]);
+        samples = [0; 4];
+        samples_ptr = samples.as_mut_ptr() as *mut raw::c_void;
+        let stats = finish_output::<u8>(samples_ptr, 4, &options(Limiter::Hard, 0.5));
+        assert_eq!(samples, [0x40; 4]);
+        assert_eq!(stats.limited, 4);
+    }
+
+    #[test]
+    fn safety_counters_accumulate_stats() {
+        let counters = SafetyCounters::default();
+        assert_eq!(counters.stats(), SafetyStats::default());
+        let mut samples = [f32::NAN, 2.0, 0.5];
+        counters.add(finish_samples(&mut samples, &options(Limiter::Hard, 1.0)));
+        let mut samples = [2.0, -2.0, f32::INFINITY];
+        counters.add(finish_samples(&mut samples, &options(Limiter::Hard, 1.0)));
+        let expected = SafetyStats {
+            non_finite: 2,
+            limited: 3,
+        };
+        assert_eq!(counters.stats(), expected);
+    }
+
+    #[test]
+    fn no_safety_stage_leaves_samples_unchanged() {
+        let mut samples = [f32::NAN, 2.0];
+        let stats = finish_samples(&mut samples, &OutputOptions::default());
+        assert_eq!(stats, SafetyStats::default());
+        assert!(samples[0].is_nan());
+        assert_eq!(samples[1], 2.0);
+    }
 }
@@ -24,7 +24,7 @@
 #![allow(dead_code)]
 
 use ffi;
-use num::FromPrimitive;
+use std::ffi::CString;
 use std::os::raw;
 
 pub use self::sample_format_flags::SampleFormatFlags;
@@ -69,6 +69,15 @@ impl From<DeviceKind> for ffi::PaDeviceIndex {
     }
 }
 
+/// The direction in which audio flows through a device.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// Audio is received from the device's ADC.
+    Input,
+    /// Audio is sent to the device's DAC.
+    Output,
+}
+
 /// The special value may be used to request that the stream callback will receive an optimal (and
 /// possibly varying) number of frames based on host requirements and the requested latency
 /// settings.
@@ -110,14 +119,15 @@ pub enum SampleFormat {
     /// The following e-mail by Bencina (2004) touches on the topic of custom formats:
     ///
     /// > "It is theoretically possible to pass "custom" data formats to PortAudio using the
-    /// paCustomFormat SampleFormat flag. I think the general idea is that when this bit is set,
-    /// the low word of the sample format byte is device specific. I know of no implementation that
-    /// has ever used this feature so it has not been extensively developed. That said, much of
-    /// PortAudio (V19 at least) assumes a frame based sample format, accomodating a block based
-    /// format such as mpeg would probably require bypassing some of the internal infrastructure
-    /// (such as the block adapter in pa_process). PortAudio has been designed for linear, frame
-    /// based i/o, so it would be up to you to propose/suggest ways in which to accomodate your
-    /// requirements." - http://music.columbia.edu/pipermail/portaudio/2004-February/003237.html
+    /// > paCustomFormat SampleFormat flag. I think the general idea is that when this bit is set,
+    /// > the low word of the sample format byte is device specific. I know of no implementation
+    /// > that has ever used this feature so it has not been extensively developed. That said, much
+    /// > of PortAudio (V19 at least) assumes a frame based sample format, accomodating a block
+    /// > based format such as mpeg would probably require bypassing some of the internal
+    /// > infrastructure (such as the block adapter in pa_process). PortAudio has been designed for
+    /// > linear, frame based i/o, so it would be up to you to propose/suggest ways in which to
+    /// > accomodate your requirements." -
+    /// > http://music.columbia.edu/pipermail/portaudio/2004-February/003237.html
     Custom,
     /// This variant is used when none of the above variants can be inferred from a given
     /// set of **SampleFormatFlags** via the `SampleFormat::from_flags` function.
@@ -219,7 +229,7 @@ pub mod sample_format_flags {
 
     impl From<ffi::SampleFormat> for SampleFormatFlags {
         fn from(format: ffi::SampleFormat) -> Self {
-            SampleFormatFlags::from_bits(format).unwrap_or_else(|| SampleFormatFlags::empty())
+            SampleFormatFlags::from_bits(format).unwrap_or_else(SampleFormatFlags::empty)
         }
     }
 
@@ -244,40 +254,96 @@ pub mod sample_format_flags {
     }
 }
 
-enum_from_primitive! {
 /// Unchanging unique identifiers for each supported host API
-#[repr(u32)]
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
 pub enum HostApiTypeId {
     /// In development host
-    InDevelopment = ffi::PaHostApiTypeId_paInDevelopment,
+    InDevelopment,
     /// Direct sound
-    DirectSound = ffi::PaHostApiTypeId_paDirectSound,
+    DirectSound,
     /// MMe API
-    MME = ffi::PaHostApiTypeId_paMME,
+    MME,
     /// ASIO API
-    ASIO = ffi::PaHostApiTypeId_paASIO,
+    ASIO,
     /// Sound manager API
-    SoundManager = ffi::PaHostApiTypeId_paSoundManager,
+    SoundManager,
     /// Core Audio API
-    CoreAudio = ffi::PaHostApiTypeId_paCoreAudio,
+    CoreAudio,
     /// OSS API
-    OSS = ffi::PaHostApiTypeId_paOSS,
+    OSS,
     /// Alsa API
-    ALSA = ffi::PaHostApiTypeId_paALSA,
+    ALSA,
     /// AL API
-    AL = ffi::PaHostApiTypeId_paAL,
+    AL,
     /// BeOS API
-    BeOS = ffi::PaHostApiTypeId_paBeOS,
+    BeOS,
     /// WDMKS
-    WDMKS = ffi::PaHostApiTypeId_paWDMKS,
+    WDMKS,
     /// Jack API
-    JACK = ffi::PaHostApiTypeId_paJACK,
+    JACK,
     /// WASAPI
-    WASAPI = ffi::PaHostApiTypeId_paWASAPI,
+    WASAPI,
     /// Audio Science HPI
-    AudioScienceHPI = ffi::PaHostApiTypeId_paAudioScienceHPI,
+    AudioScienceHPI,
+    /// AudioIO API
+    AudioIO,
+    /// PulseAudio API
+    PulseAudio,
+    /// sndio API
+    Sndio,
+    /// A host API unknown to this version of rust-portaudio, most likely added in a newer version
+    /// of PortAudio.
+    Unknown(u32),
 }
+
+impl From<ffi::PaHostApiTypeId> for HostApiTypeId {
+    fn from(id: ffi::PaHostApiTypeId) -> Self {
+        match id {
+            ffi::PaHostApiTypeId_paInDevelopment => HostApiTypeId::InDevelopment,
+            ffi::PaHostApiTypeId_paDirectSound => HostApiTypeId::DirectSound,
+            ffi::PaHostApiTypeId_paMME => HostApiTypeId::MME,
+            ffi::PaHostApiTypeId_paASIO => HostApiTypeId::ASIO,
+            ffi::PaHostApiTypeId_paSoundManager => HostApiTypeId::SoundManager,
+            ffi::PaHostApiTypeId_paCoreAudio => HostApiTypeId::CoreAudio,
+            ffi::PaHostApiTypeId_paOSS => HostApiTypeId::OSS,
+            ffi::PaHostApiTypeId_paALSA => HostApiTypeId::ALSA,
+            ffi::PaHostApiTypeId_paAL => HostApiTypeId::AL,
+            ffi::PaHostApiTypeId_paBeOS => HostApiTypeId::BeOS,
+            ffi::PaHostApiTypeId_paWDMKS => HostApiTypeId::WDMKS,
+            ffi::PaHostApiTypeId_paJACK => HostApiTypeId::JACK,
+            ffi::PaHostApiTypeId_paWASAPI => HostApiTypeId::WASAPI,
+            ffi::PaHostApiTypeId_paAudioScienceHPI => HostApiTypeId::AudioScienceHPI,
+            ffi::PaHostApiTypeId_paAudioIO => HostApiTypeId::AudioIO,
+            ffi::PaHostApiTypeId_paPulseAudio => HostApiTypeId::PulseAudio,
+            ffi::PaHostApiTypeId_paSndio => HostApiTypeId::Sndio,
+            id => HostApiTypeId::Unknown(id),
+        }
+    }
+}
+
+impl From<HostApiTypeId> for ffi::PaHostApiTypeId {
+    fn from(id: HostApiTypeId) -> Self {
+        match id {
+            HostApiTypeId::InDevelopment => ffi::PaHostApiTypeId_paInDevelopment,
+            HostApiTypeId::DirectSound => ffi::PaHostApiTypeId_paDirectSound,
+            HostApiTypeId::MME => ffi::PaHostApiTypeId_paMME,
+            HostApiTypeId::ASIO => ffi::PaHostApiTypeId_paASIO,
+            HostApiTypeId::SoundManager => ffi::PaHostApiTypeId_paSoundManager,
+            HostApiTypeId::CoreAudio => ffi::PaHostApiTypeId_paCoreAudio,
+            HostApiTypeId::OSS => ffi::PaHostApiTypeId_paOSS,
+            HostApiTypeId::ALSA => ffi::PaHostApiTypeId_paALSA,
+            HostApiTypeId::AL => ffi::PaHostApiTypeId_paAL,
+            HostApiTypeId::BeOS => ffi::PaHostApiTypeId_paBeOS,
+            HostApiTypeId::WDMKS => ffi::PaHostApiTypeId_paWDMKS,
+            HostApiTypeId::JACK => ffi::PaHostApiTypeId_paJACK,
+            HostApiTypeId::WASAPI => ffi::PaHostApiTypeId_paWASAPI,
+            HostApiTypeId::AudioScienceHPI => ffi::PaHostApiTypeId_paAudioScienceHPI,
+            HostApiTypeId::AudioIO => ffi::PaHostApiTypeId_paAudioIO,
+            HostApiTypeId::PulseAudio => ffi::PaHostApiTypeId_paPulseAudio,
+            HostApiTypeId::Sndio => ffi::PaHostApiTypeId_paSndio,
+            HostApiTypeId::Unknown(id) => id,
+        }
+    }
 }
 
 /// A structure containing information about a particular host API.
@@ -303,7 +369,6 @@ impl<'a> HostApiInfo<'a> {
     /// Returns `None` if:
     /// - either of the given device indices are invalid.
     /// - the device_count is less than `0`.
-    /// - a valid `HostApiTypeId` can't be constructed from the given `host_type`.
     pub fn from_c_info(c_info: ffi::PaHostApiInfo) -> Option<HostApiInfo<'a>> {
         let default_input_device = match c_info.defaultInputDevice {
             idx if idx >= 0 => Some(DeviceIndex(idx as u32)),
@@ -319,22 +384,36 @@ impl<'a> HostApiInfo<'a> {
             n if n >= 0 => n as u32,
             _ => return None,
         };
-        let host_type = match FromPrimitive::from_u32(c_info.type_) {
-            Some(ty) => ty,
-            None => return None,
-        };
         Some(HostApiInfo {
             struct_version: c_info.structVersion,
-            host_type: host_type,
+            host_type: c_info.type_.into(),
             name: ffi::c_str_to_str(c_info.name).unwrap_or("<Failed to convert str from CStr>"),
-            device_count: device_count,
-            default_input_device: default_input_device,
-            default_output_device: default_output_device,
+            device_count,
+            default_input_device,
+            default_output_device,
         })
     }
 }
 
-impl<'a> From<HostApiInfo<'a>> for ffi::PaHostApiInfo {
+/// An owned C representation of a **HostApiInfo**.
+///
+/// Owns a NUL-terminated copy of the host API's name, which the `ffi::PaHostApiInfo` borrowed via
+/// **CHostApiInfo::as_c_info** points to.
+#[derive(Debug)]
+pub struct CHostApiInfo {
+    name: CString,
+    c_info: ffi::PaHostApiInfo,
+}
+
+impl CHostApiInfo {
+    /// Borrow the C struct, which is only valid for as long as `self`.
+    pub fn as_c_info(&self) -> &ffi::PaHostApiInfo {
+        &self.c_info
+    }
+}
+
+impl<'a> From<HostApiInfo<'a>> for CHostApiInfo {
+    /// The name is truncated at its first NUL byte, if any.
     fn from(info: HostApiInfo<'a>) -> Self {
         let default_input_device = match info.default_input_device {
             Some(i) => i.into(),
@@ -344,15 +423,121 @@ impl<'a> From<HostApiInfo<'a>> for ffi::PaHostApiInfo {
             Some(i) => i.into(),
             None => ffi::PA_NO_DEVICE,
         };
-        ffi::PaHostApiInfo {
+        let name = to_c_string(info.name);
+        let c_info = ffi::PaHostApiInfo {
             structVersion: info.struct_version as raw::c_int,
-            type_: info.host_type as ffi::PaHostApiTypeId,
-            name: ffi::str_to_c_str(info.name),
+            type_: info.host_type.into(),
+            name: name.as_ptr(),
             deviceCount: info.device_count as raw::c_int,
             defaultInputDevice: default_input_device,
             defaultOutputDevice: default_output_device,
+        };
+        CHostApiInfo { name, c_info }
+    }
+}
+
+/// The version of a PortAudio library.
+///
+/// **VersionInfo**s are compared and ordered by their `major`, `minor` and `sub_minor` numbers
+/// alone. The `control_revision` and `text` are purely descriptive.
+#[derive(Copy, Clone, Debug)]
+pub struct VersionInfo {
+    /// The major version number
+    pub major: u32,
+    /// The minor version number
+    pub minor: u32,
+    /// The sub-minor version number
+    pub sub_minor: u32,
+    /// The revision of the PortAudio source from which the library was built, or an empty string
+    /// if it is unknown
+    pub control_revision: &'static str,
+    /// A textual description of the version, for example
+    /// `"PortAudio V19.7.0-devel, revision 147dd72"`
+    pub text: &'static str,
+}
+
+impl VersionInfo {
+    /// Construct a **VersionInfo** with the given version numbers and empty descriptions.
+    ///
+    /// Useful for describing the minimum version required by **PortAudio::require_version**.
+    pub fn new(major: u32, minor: u32, sub_minor: u32) -> Self {
+        VersionInfo {
+            major,
+            minor,
+            sub_minor,
+            control_revision: "",
+            text: "",
         }
     }
+
+    /// Construct a **VersionInfo** from a version number as returned by `Pa_GetVersion`, along
+    /// with the given textual description.
+    ///
+    /// Builds of PortAudio prior to 19.5.0 returned a plain release number such as `1899` rather
+    /// than the encoded version, so any number below `0x10000` is taken to be version 19.0.0.
+    pub fn from_version_number(number: i32, text: &'static str) -> Self {
+        if (number as u32) < 0x10000 {
+            return VersionInfo {
+                text,
+                ..VersionInfo::new(19, 0, 0)
+            };
+        }
+        VersionInfo {
+            text,
+            ..VersionInfo::new(
+                (number >> 16) as u32 & 0xff,
+                (number >> 8) as u32 & 0xff,
+                number as u32 & 0xff,
+            )
+        }
+    }
+
+    /// Construct the VersionInfo from the equivalent C struct.
+    pub fn from_c_info(c_info: ffi::PaVersionInfo) -> Self {
+        VersionInfo {
+            major: c_info.versionMajor as u32,
+            minor: c_info.versionMinor as u32,
+            sub_minor: c_info.versionSubMinor as u32,
+            control_revision: ffi::c_str_to_str(c_info.versionControlRevision).unwrap_or(""),
+            text: ffi::c_str_to_str(c_info.versionText)
+                .unwrap_or("<Failed to convert str from CStr>"),
+        }
+    }
+
+    /// The version as a number in the format returned by `Pa_GetVersion`.
+    pub fn version_number(&self) -> i32 {
+        ((self.major << 16) | (self.minor << 8) | self.sub_minor) as i32
+    }
+
+    fn numbers(&self) -> (u32, u32, u32) {
+        (self.major, self.minor, self.sub_minor)
+    }
+}
+
+impl PartialEq for VersionInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.numbers() == other.numbers()
+    }
+}
+
+impl Eq for VersionInfo {}
+
+impl PartialOrd for VersionInfo {
+    fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for VersionInfo {
+    fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+        self.numbers().cmp(&other.numbers())
+    }
+}
+
+impl ::std::fmt::Display for VersionInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.sub_minor)
+    }
 }
 
 /// Structure used to return information about a host error condition.
@@ -370,7 +555,7 @@ impl<'a> HostErrorInfo<'a> {
     /// Construct a HostErrorInfo from the equivalent C struct.
     pub fn from_c_error_info(c_error: ffi::PaHostErrorInfo) -> HostErrorInfo<'a> {
         HostErrorInfo {
-            host_api_type: FromPrimitive::from_u32(c_error.hostApiType).unwrap(),
+            host_api_type: c_error.hostApiType.into(),
             code: c_error.errorCode as u32,
             text: ffi::c_str_to_str(c_error.errorText)
                 .unwrap_or("<Failed to convert str from CStr>"),
@@ -378,13 +563,33 @@ impl<'a> HostErrorInfo<'a> {
     }
 }
 
-impl<'a> From<HostErrorInfo<'a>> for ffi::PaHostErrorInfo {
+/// An owned C representation of a **HostErrorInfo**.
+///
+/// Owns a NUL-terminated copy of the error text, which the `ffi::PaHostErrorInfo` borrowed via
+/// **CHostErrorInfo::as_c_info** points to.
+#[derive(Debug)]
+pub struct CHostErrorInfo {
+    text: CString,
+    c_info: ffi::PaHostErrorInfo,
+}
+
+impl CHostErrorInfo {
+    /// Borrow the C struct, which is only valid for as long as `self`.
+    pub fn as_c_info(&self) -> &ffi::PaHostErrorInfo {
+        &self.c_info
+    }
+}
+
+impl<'a> From<HostErrorInfo<'a>> for CHostErrorInfo {
+    /// The text is truncated at its first NUL byte, if any.
     fn from(error: HostErrorInfo<'a>) -> Self {
-        ffi::PaHostErrorInfo {
-            hostApiType: FromPrimitive::from_i32(error.host_api_type as i32).unwrap(),
+        let text = to_c_string(error.text);
+        let c_info = ffi::PaHostErrorInfo {
+            hostApiType: error.host_api_type.into(),
             errorCode: error.code as raw::c_long,
-            errorText: ffi::str_to_c_str(error.text),
-        }
+            errorText: text.as_ptr(),
+        };
+        CHostErrorInfo { text, c_info }
     }
 }
 
@@ -433,11 +638,30 @@ impl<'a> DeviceInfo<'a> {
     }
 }
 
-impl<'a> From<DeviceInfo<'a>> for ffi::PaDeviceInfo {
+/// An owned C representation of a **DeviceInfo**.
+///
+/// Owns a NUL-terminated copy of the device's name, which the `ffi::PaDeviceInfo` borrowed via
+/// **CDeviceInfo::as_c_info** points to.
+#[derive(Debug)]
+pub struct CDeviceInfo {
+    name: CString,
+    c_info: ffi::PaDeviceInfo,
+}
+
+impl CDeviceInfo {
+    /// Borrow the C struct, which is only valid for as long as `self`.
+    pub fn as_c_info(&self) -> &ffi::PaDeviceInfo {
+        &self.c_info
+    }
+}
+
+impl<'a> From<DeviceInfo<'a>> for CDeviceInfo {
+    /// The name is truncated at its first NUL byte, if any.
     fn from(info: DeviceInfo<'a>) -> Self {
-        ffi::PaDeviceInfo {
+        let name = to_c_string(info.name);
+        let c_info = ffi::PaDeviceInfo {
             structVersion: info.struct_version as raw::c_int,
-            name: ffi::str_to_c_str(info.name),
+            name: name.as_ptr(),
             hostApi: info.host_api as ffi::PaHostApiIndex,
             maxInputChannels: info.max_input_channels as raw::c_int,
             maxOutputChannels: info.max_output_channels as raw::c_int,
@@ -446,6 +670,213 @@ impl<'a> From<DeviceInfo<'a>> for ffi::PaDeviceInfo {
             defaultHighInputLatency: info.default_high_input_latency,
             defaultHighOutputLatency: info.default_high_output_latency,
             defaultSampleRate: info.default_sample_rate,
+        };
+        CDeviceInfo { name, c_info }
+    }
+}
+
+/// Copy `s` into a NUL-terminated C string, truncating it at its first NUL byte if any.
+fn to_c_string(s: &str) -> CString {
+    let bytes = s.as_bytes();
+    let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    CString::new(&bytes[..len]).expect("the string was truncated at its first NUL byte")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CDeviceInfo, CHostApiInfo, CHostErrorInfo, DeviceIndex, DeviceInfo, HostApiInfo,
+        HostApiTypeId, HostErrorInfo, VersionInfo,
+    };
+    use ffi;
+    use std::ffi::{CStr, CString};
+
+    fn device_info<'a>(name: &'a str) -> DeviceInfo<'a> {
+        DeviceInfo {
+            struct_version: 2,
+            name,
+            host_api: 1,
+            max_input_channels: 2,
+            max_output_channels: 8,
+            default_low_input_latency: 0.01,
+            default_low_output_latency: 0.02,
+            default_high_input_latency: 0.1,
+            default_high_output_latency: 0.2,
+            default_sample_rate: 48_000.0,
+        }
+    }
+
+    fn host_api_info<'a>(name: &'a str) -> HostApiInfo<'a> {
+        HostApiInfo {
+            struct_version: 1,
+            host_type: HostApiTypeId::ALSA,
+            name,
+            device_count: 3,
+            default_input_device: None,
+            default_output_device: Some(DeviceIndex(2)),
+        }
+    }
+
+    fn host_error_info<'a>(text: &'a str) -> HostErrorInfo<'a> {
+        HostErrorInfo {
+            host_api_type: HostApiTypeId::Unknown(1000),
+            code: 42,
+            text,
+        }
+    }
+
+    #[test]
+    fn version_number_is_decoded() {
+        let version = VersionInfo::from_version_number(0x13_07_00, "PortAudio V19.7.0");
+        assert_eq!(version, VersionInfo::new(19, 7, 0));
+        assert_eq!(version.text, "PortAudio V19.7.0");
+        assert_eq!(version.version_number(), 0x13_07_00);
+        assert_eq!(version.to_string(), "19.7.0");
+    }
+
+    #[test]
+    fn legacy_version_number_is_version_19() {
+        let version = VersionInfo::from_version_number(1899, "PortAudio V19-devel");
+        assert_eq!(version, VersionInfo::new(19, 0, 0));
+        assert_eq!(version.text, "PortAudio V19-devel");
+        assert!(version < VersionInfo::new(19, 5, 0));
+        assert_eq!(VersionInfo::from_version_number(0xffff, ""), version);
+    }
+
+    #[test]
+    fn versions_are_ordered_by_number() {
+        let versions = [
+            VersionInfo::new(18, 9, 9),
+            VersionInfo::new(19, 0, 0),
+            VersionInfo::new(19, 5, 0),
+            VersionInfo::new(19, 6, 0),
+            VersionInfo::new(19, 6, 1),
+            VersionInfo::new(19, 7, 0),
+            VersionInfo::new(20, 0, 0),
+        ];
+        for pair in versions.windows(2) {
+            assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]);
+            assert!(pair[0].version_number() < pair[1].version_number());
+        }
+    }
+
+    #[test]
+    fn descriptions_are_ignored_by_comparison() {
+        let mut version = VersionInfo::new(19, 7, 0);
+        version.control_revision = "147dd72";
+        version.text = "PortAudio V19.7.0-devel, revision 147dd72";
+        assert_eq!(version, VersionInfo::new(19, 7, 0));
+        assert_eq!(
+            version.cmp(&VersionInfo::new(19, 7, 0)),
+            ::std::cmp::Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn known_host_api_type_ids_round_trip() {
+        let host_apis = [
+            HostApiTypeId::InDevelopment,
+            HostApiTypeId::DirectSound,
+            HostApiTypeId::MME,
+            HostApiTypeId::ASIO,
+            HostApiTypeId::SoundManager,
+            HostApiTypeId::CoreAudio,
+            HostApiTypeId::OSS,
+            HostApiTypeId::ALSA,
+            HostApiTypeId::AL,
+            HostApiTypeId::BeOS,
+            HostApiTypeId::WDMKS,
+            HostApiTypeId::JACK,
+            HostApiTypeId::WASAPI,
+            HostApiTypeId::AudioScienceHPI,
+            HostApiTypeId::AudioIO,
+            HostApiTypeId::PulseAudio,
+            HostApiTypeId::Sndio,
+        ];
+        for &host_api in &host_apis {
+            let id = ffi::PaHostApiTypeId::from(host_api);
+            assert_eq!(HostApiTypeId::from(id), host_api);
         }
+        // PortAudio never assigned the type id 6.
+        assert_eq!(HostApiTypeId::from(6), HostApiTypeId::Unknown(6));
+    }
+
+    #[test]
+    fn unknown_host_api_type_ids_round_trip() {
+        for &id in &[ffi::PaHostApiTypeId_paSndio + 1, 1000, u32::MAX] {
+            let host_api = HostApiTypeId::from(id);
+            assert_eq!(host_api, HostApiTypeId::Unknown(id));
+            assert_eq!(ffi::PaHostApiTypeId::from(host_api), id);
+        }
+    }
+
+    #[test]
+    fn host_error_with_unknown_host_api() {
+        let text = CString::new("device exploded").unwrap();
+        let c_error = ffi::PaHostErrorInfo {
+            hostApiType: 1000,
+            errorCode: -5,
+            errorText: text.as_ptr(),
+        };
+        let info = HostErrorInfo::from_c_error_info(c_error);
+        assert_eq!(info.host_api_type, HostApiTypeId::Unknown(1000));
+        assert_eq!(info.text, "device exploded");
+    }
+
+    #[test]
+    fn c_device_info_round_trips() {
+        let c_device = CDeviceInfo::from(device_info("USB Audio: hw:1,0"));
+        assert_eq!(c_device.as_c_info().name, c_device.name.as_ptr());
+        let info = DeviceInfo::from_c_info(*c_device.as_c_info());
+        assert_eq!(info, device_info("USB Audio: hw:1,0"));
+    }
+
+    #[test]
+    fn c_host_api_info_round_trips() {
+        let c_host_api = CHostApiInfo::from(host_api_info("ALSA"));
+        let c_info = c_host_api.as_c_info();
+        assert_eq!(c_info.name, c_host_api.name.as_ptr());
+        assert_eq!(c_info.defaultInputDevice, ffi::PA_NO_DEVICE);
+        let info = HostApiInfo::from_c_info(*c_info).unwrap();
+        assert_eq!(info, host_api_info("ALSA"));
+    }
+
+    #[test]
+    fn c_host_error_info_round_trips() {
+        let c_error = CHostErrorInfo::from(host_error_info("device exploded"));
+        assert_eq!(c_error.as_c_info().errorText, c_error.text.as_ptr());
+        let info = HostErrorInfo::from_c_error_info(*c_error.as_c_info());
+        assert_eq!(info, host_error_info("device exploded"));
+    }
+
+    #[test]
+    fn interior_nul_truncates_names() {
+        let c_device = CDeviceInfo::from(device_info("USB\0Audio"));
+        let name = unsafe { CStr::from_ptr(c_device.as_c_info().name) };
+        assert_eq!(name.to_bytes(), b"USB");
+
+        let c_host_api = CHostApiInfo::from(host_api_info("\0ALSA"));
+        let name = unsafe { CStr::from_ptr(c_host_api.as_c_info().name) };
+        assert_eq!(name.to_bytes(), b"");
+
+        let c_error = CHostErrorInfo::from(host_error_info("device\0exploded\0"));
+        let text = unsafe { CStr::from_ptr(c_error.as_c_info().errorText) };
+        assert_eq!(text.to_bytes(), b"device");
+    }
+
+    #[test]
+    fn c_info_outlives_borrowed_name() {
+        let c_device = {
+            let name = String::from("Transient");
+            CDeviceInfo::from(device_info(&name))
+        };
+        // Moving the owner doesn't move the name that the C struct points to.
+        let moved = [c_device];
+        let c_info = moved[0].as_c_info();
+        assert_eq!(c_info.name, moved[0].name.as_ptr());
+        assert_eq!(
+            unsafe { CStr::from_ptr(c_info.name) }.to_bytes(),
+            b"Transient"
+        );
     }
 }
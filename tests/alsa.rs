@@ -0,0 +1,50 @@
+//! Checks that an **AlsaStreamInfo** opens ALSA PCMs by name.
+//!
+//! These tests require a PortAudio installation including the ALSA host API. They use ALSA's
+//! built-in `null` PCM, which PortAudio does not enumerate and which needs no sound card.
+
+#![cfg(portaudio_alsa)]
+
+extern crate portaudio as pa;
+
+use pa::ext::alsa::AlsaStreamInfo;
+
+const CHANNELS: i32 = 2;
+const SAMPLE_RATE: f64 = 44_100.0;
+const FRAMES: u32 = 256;
+
+fn null_pcm_params() -> pa::StreamParameters<f32> {
+    let info = AlsaStreamInfo::new("null").unwrap();
+    pa::StreamParameters::host_api_specific_device(CHANNELS, true, 0.1)
+        .with_host_api_specific_stream_info(info)
+}
+
+#[test]
+fn null_pcm_format_is_supported() {
+    let pa = pa::PortAudio::new().unwrap();
+    pa.is_output_format_supported(null_pcm_params(), SAMPLE_RATE)
+        .unwrap();
+}
+
+#[test]
+fn null_pcm_may_be_written_to() {
+    let pa = pa::PortAudio::new().unwrap();
+    let settings = pa::OutputStreamSettings::new(null_pcm_params(), SAMPLE_RATE, FRAMES);
+    let mut stream = pa.open_blocking_stream(settings).unwrap();
+    stream.start().unwrap();
+    for _ in 0..4 {
+        stream
+            .write(FRAMES, |buffer| {
+                for sample in buffer.iter_mut() {
+                    *sample = 0.0;
+                }
+            })
+            .unwrap();
+    }
+    stream.stop().unwrap();
+}
+
+#[test]
+fn device_string_may_not_contain_nul() {
+    assert!(AlsaStreamInfo::new("nu\0ll").is_err());
+}
@@ -0,0 +1,175 @@
+//! Checks the options of a **PortAudioBuilder**.
+//!
+//! These tests require a PortAudio installation with at least one host API.
+
+extern crate portaudio as pa;
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Mutex;
+
+/// A host API that is never available.
+const ABSENT: pa::HostApiTypeId = pa::HostApiTypeId::Unknown(u32::MAX);
+
+/// Held by tests whose instances must be the only ones alive within the process.
+static EXCLUSIVE: Mutex<()> = Mutex::new(());
+
+fn exclusive() -> std::sync::MutexGuard<'static, ()> {
+    EXCLUSIVE.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+#[test]
+fn excluded_host_apis_are_skipped() {
+    let _guard = exclusive();
+    let pa = pa::PortAudio::builder()
+        .host_apis(vec![ABSENT])
+        .build()
+        .unwrap();
+    assert_eq!(pa.host_apis().unwrap().count(), 0);
+    assert_eq!(pa.devices().unwrap().count(), 0);
+    let type_id = pa.host_api_info(0).unwrap().host_type;
+    let error = pa.host_api(type_id).unwrap_err();
+    assert_eq!(error.kind(), pa::ErrorKind::HostApiNotFound);
+    let error = pa.default_host_api().unwrap_err();
+    assert_eq!(error.kind(), pa::ErrorKind::HostApiNotFound);
+    assert!(pa.default_output_device().is_err());
+}
+
+#[test]
+fn considered_host_apis_are_unaffected() {
+    let _guard = exclusive();
+    let pa = pa::PortAudio::new().unwrap();
+    let type_ids: Vec<_> = pa
+        .host_apis()
+        .unwrap()
+        .map(|host_api| host_api.unwrap().type_id())
+        .collect();
+    let restricted = pa::PortAudio::builder()
+        .host_apis(type_ids.clone())
+        .build()
+        .unwrap();
+    let restricted_type_ids: Vec<_> = restricted
+        .host_apis()
+        .unwrap()
+        .map(|h| h.unwrap().type_id())
+        .collect();
+    assert_eq!(restricted_type_ids, type_ids);
+    assert_eq!(
+        restricted.devices().unwrap().count(),
+        pa.devices().unwrap().count()
+    );
+    assert_eq!(
+        restricted.default_host_api().unwrap(),
+        pa.default_host_api().unwrap()
+    );
+}
+
+#[test]
+fn default_host_api_is_preferred_if_available() {
+    let _guard = exclusive();
+    let pa = pa::PortAudio::new().unwrap();
+    let host_api = pa.host_apis().unwrap().last().unwrap().unwrap();
+    let preferring = pa::PortAudio::builder()
+        .default_host_api(host_api.type_id())
+        .build()
+        .unwrap();
+    assert_eq!(preferring.default_host_api().unwrap(), host_api.index());
+    let expected = host_api.info().default_output_device;
+    assert_eq!(preferring.default_output_device().ok(), expected);
+
+    let preferring_absent = pa::PortAudio::builder()
+        .default_host_api(ABSENT)
+        .build()
+        .unwrap();
+    assert_eq!(
+        preferring_absent.default_host_api().unwrap(),
+        pa.default_host_api().unwrap()
+    );
+}
+
+#[test]
+fn pre_init_hooks_run_in_order_before_initialisation() {
+    let _guard = exclusive();
+    let calls = Rc::new(Cell::new(0));
+    let (first, second) = (calls.clone(), calls.clone());
+    let _pa = pa::PortAudio::builder()
+        .pre_init(move || {
+            assert_eq!(first.replace(1), 0);
+            Ok(())
+        })
+        .pre_init(move || {
+            assert_eq!(second.replace(2), 1);
+            Ok(())
+        })
+        .build()
+        .unwrap();
+    assert_eq!(calls.get(), 2);
+
+    let hook_calls = calls.clone();
+    let error = pa::PortAudio::builder()
+        .pre_init(move || {
+            hook_calls.set(3);
+            Ok(())
+        })
+        .build()
+        .unwrap_err();
+    assert_eq!(error.kind(), pa::ErrorKind::OtherInstancesAlive);
+    assert_eq!(calls.get(), 2);
+}
+
+#[test]
+fn pre_init_hooks_are_not_called_again_by_a_rescan() {
+    let _guard = exclusive();
+    let calls = Rc::new(Cell::new(0));
+    let hook_calls = calls.clone();
+    let mut pa = pa::PortAudio::builder()
+        .pre_init(move || {
+            hook_calls.set(hook_calls.get() + 1);
+            Ok(())
+        })
+        .build()
+        .unwrap();
+    pa.rescan_devices().unwrap();
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn failing_pre_init_hook_aborts_initialisation() {
+    let _guard = exclusive();
+    let error = pa::PortAudio::builder()
+        .pre_init(|| Err(pa::Error::new(pa::ErrorKind::InvalidFlag)))
+        .build()
+        .unwrap_err();
+    assert_eq!(error.kind(), pa::ErrorKind::InvalidFlag);
+    // The failed build must not have left PortAudio initialised.
+    pa::PortAudio::builder()
+        .pre_init(|| Ok(()))
+        .build()
+        .unwrap();
+}
+
+#[test]
+fn required_version_is_checked_at_initialisation() {
+    let _guard = exclusive();
+    let pa = pa::PortAudio::builder()
+        .require_version(pa::VersionInfo::new(19, 0, 0))
+        .build()
+        .unwrap();
+    let newer = pa::VersionInfo::new(pa.version_info().major + 1, 0, 0);
+    drop(pa);
+    let error = pa::PortAudio::builder()
+        .require_version(newer)
+        .build()
+        .unwrap_err();
+    match error.kind() {
+        pa::ErrorKind::UnsupportedVersion { required, .. } => {
+            assert_eq!(required, newer.version_number())
+        }
+        kind => panic!("unexpected error kind: {:?}", kind),
+    }
+    // The failed build must not have left PortAudio initialised.
+    pa::PortAudio::builder()
+        .pre_init(|| Ok(()))
+        .build()
+        .unwrap();
+}
@@ -0,0 +1,74 @@
+//! Checks that host API specific stream info is rejected by devices of another host API.
+//!
+//! These tests require a PortAudio installation and are skipped when no default output device is
+//! available.
+
+extern crate portaudio as pa;
+
+use std::os::raw::c_void;
+use std::ptr;
+
+const CHANNELS: i32 = 2;
+const SAMPLE_RATE: f64 = 44_100.0;
+const FRAMES: u32 = 256;
+
+/// Stream info for a host API that no device belongs to.
+#[derive(Clone, PartialEq, Debug)]
+struct ForeignStreamInfo;
+
+unsafe impl pa::HostApiSpecificStreamInfo for ForeignStreamInfo {
+    fn host_api_type(&self) -> pa::HostApiTypeId {
+        pa::HostApiTypeId::Unknown(u32::MAX)
+    }
+
+    fn as_ptr(&self) -> *const c_void {
+        ptr::null()
+    }
+}
+
+/// Output settings for the default output device carrying **ForeignStreamInfo**, or `None` if
+/// there is no such device.
+fn foreign_output_settings(pa: &pa::PortAudio) -> Option<pa::OutputStreamSettings<f32>> {
+    let mut settings = pa
+        .default_output_stream_settings::<f32>(CHANNELS, SAMPLE_RATE, FRAMES)
+        .ok()?;
+    settings.params.host_api_specific_stream_info = Some(Box::new(ForeignStreamInfo));
+    Some(settings)
+}
+
+fn assert_incompatible<T>(result: Result<T, pa::Error>) {
+    match result {
+        Ok(_) => panic!("expected an IncompatibleHostApiSpecificStreamInfo error"),
+        Err(error) => assert_eq!(
+            error.kind(),
+            pa::ErrorKind::IncompatibleHostApiSpecificStreamInfo
+        ),
+    }
+}
+
+#[test]
+fn foreign_info_is_not_format_supported() {
+    let pa = pa::PortAudio::new().unwrap();
+    if let Some(settings) = foreign_output_settings(&pa) {
+        assert_incompatible(pa.is_output_format_supported(settings.params, SAMPLE_RATE));
+    }
+}
+
+#[test]
+fn foreign_info_may_not_be_opened() {
+    let pa = pa::PortAudio::new().unwrap();
+    if let Some(settings) = foreign_output_settings(&pa) {
+        assert_incompatible(pa.open_blocking_stream(settings.clone()));
+        let callback = |_: pa::OutputStreamCallbackArgs<f32>| pa::Complete;
+        assert_incompatible(pa.open_non_blocking_stream(settings, callback));
+    }
+}
+
+#[test]
+fn boxed_info_is_compared_by_value() {
+    let params = pa::StreamParameters::<f32>::host_api_specific_device(CHANNELS, true, 0.1)
+        .with_host_api_specific_stream_info(ForeignStreamInfo);
+    assert_eq!(params.clone(), params);
+    let without = pa::StreamParameters::<f32>::host_api_specific_device(CHANNELS, true, 0.1);
+    assert!(params != without);
+}
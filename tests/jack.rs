@@ -0,0 +1,42 @@
+//! Checks that a **PortAudioBuilder** sets the JACK client name before PortAudio is initialised.
+//!
+//! These tests require a PortAudio installation including the JACK host API and a running JACK
+//! server.
+
+#![cfg(portaudio_jack)]
+
+extern crate portaudio as pa;
+
+use pa::ext::jack::{JackBuilderExt, JackPortAudioExt};
+
+fn builder() -> pa::PortAudioBuilder {
+    pa::PortAudio::builder()
+        .jack_client_name("rust-portaudio-test")
+        .unwrap()
+}
+
+// The client name requires that no other instance is alive, so the checks share a single test.
+#[test]
+fn client_name_is_set_before_initialisation() {
+    let pa = builder().build().unwrap();
+    assert!(pa
+        .jack_client_name()
+        .unwrap()
+        .starts_with("rust-portaudio-test"));
+
+    let error = builder().build().unwrap_err();
+    assert_eq!(error.kind(), pa::ErrorKind::OtherInstancesAlive);
+    // Without a client name, the builder behaves like `PortAudio::new`.
+    drop(pa::PortAudio::builder().build().unwrap());
+
+    drop(pa);
+    let pa = builder().build().unwrap();
+    assert!(pa.jack_client_name().is_ok());
+}
+
+#[test]
+fn client_name_may_not_contain_nul() {
+    assert!(pa::PortAudio::builder()
+        .jack_client_name("rust\0portaudio")
+        .is_err());
+}
@@ -0,0 +1,72 @@
+//! Checks that **PortAudio** instances and their **Stream**s may be dropped and terminated in any
+//! order.
+//!
+//! These tests require a PortAudio installation and are skipped when no default output device is
+//! available.
+
+extern crate portaudio as pa;
+
+type OutputStream = pa::Stream<pa::Blocking<pa::stream::Buffer>, pa::Output<f32>>;
+
+const CHANNELS: i32 = 2;
+const SAMPLE_RATE: f64 = 44_100.0;
+const FRAMES: u32 = 256;
+
+/// Open a blocking output stream on the default output device, or `None` if there is no such
+/// device.
+fn open_output_stream(pa: &pa::PortAudio) -> Option<OutputStream> {
+    let settings = pa
+        .default_output_stream_settings::<f32>(CHANNELS, SAMPLE_RATE, FRAMES)
+        .ok()?;
+    Some(pa.open_blocking_stream(settings).unwrap())
+}
+
+#[test]
+fn instances_may_be_dropped_in_any_order() {
+    let first = pa::PortAudio::new().unwrap();
+    let second = pa::PortAudio::new().unwrap();
+    drop(first);
+    second.device_count().unwrap();
+    let third = pa::PortAudio::new().unwrap();
+    drop(second);
+    third.device_count().unwrap();
+    third.terminate().unwrap();
+}
+
+#[test]
+fn stream_may_outlive_its_instance() {
+    let pa = pa::PortAudio::new().unwrap();
+    let mut stream = match open_output_stream(&pa) {
+        Some(stream) => stream,
+        None => return,
+    };
+    drop(pa);
+    stream.is_stopped().unwrap();
+    stream.start().unwrap();
+    stream.stop().unwrap();
+    drop(stream);
+    pa::PortAudio::new().unwrap().terminate().unwrap();
+}
+
+#[test]
+fn terminate_fails_while_streams_are_open() {
+    let pa = pa::PortAudio::new().unwrap();
+    let stream = match open_output_stream(&pa) {
+        Some(stream) => stream,
+        None => return,
+    };
+    let err = pa.terminate().unwrap_err();
+    assert_eq!(err.kind(), pa::ErrorKind::StreamsStillOpen);
+    // Termination is deferred until the stream is dropped.
+    stream.is_stopped().unwrap();
+    drop(stream);
+}
+
+#[test]
+fn terminate_succeeds_once_streams_are_dropped() {
+    let pa = pa::PortAudio::new().unwrap();
+    if let Some(stream) = open_output_stream(&pa) {
+        drop(stream);
+    }
+    pa.terminate().unwrap();
+}
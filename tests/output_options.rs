@@ -0,0 +1,89 @@
+//! Checks that output buffers are only prepared within the bounds PortAudio provides.
+//!
+//! These tests require a PortAudio installation and are skipped when no default output device is
+//! available.
+
+extern crate portaudio as pa;
+
+const CHANNELS: i32 = 2;
+const SAMPLE_RATE: f64 = 44_100.0;
+const FRAMES: u32 = 256;
+
+/// Output settings for the default output device with the given `frames_per_buffer`, or `None`
+/// if there is no such device.
+fn output_settings(
+    pa: &pa::PortAudio,
+    frames_per_buffer: u32,
+) -> Option<pa::OutputStreamSettings<f32>> {
+    let mut settings = pa
+        .default_output_stream_settings::<f32>(CHANNELS, SAMPLE_RATE, frames_per_buffer)
+        .ok()?;
+    settings.output_options.safety = Some(pa::SafetyOptions::new(pa::Limiter::Hard, 1.0));
+    Some(settings)
+}
+
+#[test]
+fn blocking_write_within_buffer() {
+    let pa = pa::PortAudio::new().unwrap();
+    let settings = match output_settings(&pa, FRAMES) {
+        Some(settings) => settings,
+        None => return,
+    };
+    let mut stream = pa.open_blocking_stream(settings).unwrap();
+    stream
+        .write(FRAMES, |buffer| {
+            assert_eq!(buffer.len(), FRAMES as usize * CHANNELS as usize);
+            assert!(buffer.iter().all(|&sample| sample == 0.0));
+            buffer[0] = 2.0;
+        })
+        .unwrap();
+    assert_eq!(stream.safety_stats().limited, 1);
+}
+
+#[test]
+fn blocking_write_beyond_buffer_is_rejected() {
+    let pa = pa::PortAudio::new().unwrap();
+    let settings = match output_settings(&pa, FRAMES) {
+        Some(settings) => settings,
+        None => return,
+    };
+    let mut stream = pa.open_blocking_stream(settings).unwrap();
+    let error = stream
+        .write(FRAMES + 1, |_| panic!("the buffer is too small"))
+        .unwrap_err();
+    assert_eq!(error.kind(), pa::ErrorKind::BufferTooBig);
+}
+
+#[test]
+fn blocking_write_with_unspecified_buffer_is_rejected() {
+    let pa = pa::PortAudio::new().unwrap();
+    let settings = match output_settings(&pa, pa::FRAMES_PER_BUFFER_UNSPECIFIED) {
+        Some(settings) => settings,
+        None => return,
+    };
+    let mut stream = pa.open_blocking_stream(settings).unwrap();
+    let error = stream
+        .write(FRAMES, |_| panic!("the buffer is empty"))
+        .unwrap_err();
+    assert_eq!(error.kind(), pa::ErrorKind::BufferTooBig);
+}
+
+fn assert_not_supported<T>(result: Result<T, pa::Error>) {
+    match result {
+        Ok(_) => panic!("expected a SampleFormatNotSupported error"),
+        Err(error) => assert_eq!(error.kind(), pa::ErrorKind::SampleFormatNotSupported),
+    }
+}
+
+#[test]
+fn non_interleaved_streams_are_rejected_at_open() {
+    let pa = pa::PortAudio::new().unwrap();
+    let mut settings = match output_settings(&pa, FRAMES) {
+        Some(settings) => settings,
+        None => return,
+    };
+    settings.params.is_interleaved = false;
+    let callback = |_: pa::OutputStreamCallbackArgs<f32>| pa::Continue;
+    assert_not_supported(pa.open_non_blocking_stream(settings.clone(), callback));
+    assert_not_supported(pa.open_blocking_stream(settings));
+}
@@ -0,0 +1,166 @@
+//! Checks the order in which a **DeviceSelector** tries its rules and the rejections it reports.
+//!
+//! These tests require a PortAudio installation and are skipped when no default output device is
+//! available.
+
+extern crate portaudio as pa;
+
+use pa::selector::{Criteria, DeviceSelector, Rejection, RejectionReason};
+
+/// A device name that no device has.
+const ABSENT_NAME: &str = "rust-portaudio absent device";
+
+/// A host API that is never available.
+const ABSENT_HOST_API: pa::HostApiTypeId = pa::HostApiTypeId::Unknown(u32::MAX);
+
+/// The default output device along with its name and number of output channels, or `None` if
+/// there is no such device.
+fn default_output(pa: &pa::PortAudio) -> Option<(pa::DeviceIndex, String, i32)> {
+    let device = pa.default_output_device().ok()?;
+    let info = pa.device_info(device).unwrap();
+    Some((device, info.name.to_string(), info.max_output_channels))
+}
+
+fn rejection(rule: usize, reason: RejectionReason) -> Rejection {
+    Rejection { rule, reason }
+}
+
+#[test]
+fn first_rule_that_matches_is_selected() {
+    let pa = pa::PortAudio::new().unwrap();
+    let (device, name, _) = match default_output(&pa) {
+        Some(default_output) => default_output,
+        None => return,
+    };
+    let selection = DeviceSelector::output(1)
+        .rule(Criteria::named(ABSENT_NAME))
+        .rule(Criteria::system_default())
+        .rule(Criteria::named(name))
+        .select::<f32>(&pa)
+        .unwrap();
+    assert_eq!(selection.rule, 1);
+    assert_eq!(selection.device, device);
+    assert_eq!(selection.params.channel_count, 1);
+    assert_eq!(
+        selection.rejections,
+        vec![rejection(0, RejectionReason::NoMatchingDevice)]
+    );
+}
+
+#[test]
+fn later_rules_are_not_tried() {
+    let pa = pa::PortAudio::new().unwrap();
+    if default_output(&pa).is_none() {
+        return;
+    }
+    let selection = DeviceSelector::output(1)
+        .rule(Criteria::system_default())
+        .rule(Criteria::named(ABSENT_NAME))
+        .select::<f32>(&pa)
+        .unwrap();
+    assert_eq!(selection.rule, 0);
+    assert!(selection.rejections.is_empty());
+}
+
+#[test]
+fn too_few_channels_is_distinguished_from_no_match() {
+    let pa = pa::PortAudio::new().unwrap();
+    let (device, _, channels) = match default_output(&pa) {
+        Some(default_output) => default_output,
+        None => return,
+    };
+    let too_few = RejectionReason::TooFewChannels {
+        device,
+        available: channels,
+        required: channels + 1,
+    };
+
+    let error = DeviceSelector::output(channels + 1)
+        .rule(Criteria::system_default())
+        .select::<f32>(&pa)
+        .unwrap_err();
+    assert_eq!(error.rejections, vec![rejection(0, too_few.clone())]);
+
+    // The rule's `min_channels` is required even when it exceeds the selector's channel count.
+    let error = DeviceSelector::output(1)
+        .rule(Criteria::system_default().min_channels(channels + 1))
+        .select::<f32>(&pa)
+        .unwrap_err();
+    assert_eq!(error.rejections, vec![rejection(0, too_few)]);
+
+    let error = DeviceSelector::output(1)
+        .rule(Criteria::any().host_api(ABSENT_HOST_API))
+        .select::<f32>(&pa)
+        .unwrap_err();
+    assert_eq!(
+        error.rejections,
+        vec![rejection(0, RejectionReason::NoMatchingDevice)]
+    );
+}
+
+#[test]
+fn rejections_accumulate_in_rule_order() {
+    let pa = pa::PortAudio::new().unwrap();
+    let (device, _, channels) = match default_output(&pa) {
+        Some(default_output) => default_output,
+        None => return,
+    };
+    let error = DeviceSelector::output(1)
+        .rule(Criteria::named(ABSENT_NAME))
+        .rule(Criteria::system_default().min_channels(channels + 1))
+        .rule(Criteria::any().host_api(ABSENT_HOST_API))
+        .select::<f32>(&pa)
+        .unwrap_err();
+    let too_few = RejectionReason::TooFewChannels {
+        device,
+        available: channels,
+        required: channels + 1,
+    };
+    assert_eq!(
+        error.rejections,
+        vec![
+            rejection(0, RejectionReason::NoMatchingDevice),
+            rejection(1, too_few),
+            rejection(2, RejectionReason::NoMatchingDevice),
+        ]
+    );
+    let message = error.to_string();
+    assert!(message.contains("rule 0: no device matched"), "{}", message);
+    assert!(message.contains("rule 1: device"), "{}", message);
+}
+
+#[test]
+fn every_matching_device_is_rejected_in_index_order() {
+    let pa = pa::PortAudio::new().unwrap();
+    if default_output(&pa).is_none() {
+        return;
+    }
+    // Devices without any output channels aren't candidates at all.
+    let output_devices: Vec<pa::DeviceIndex> = pa
+        .devices()
+        .unwrap()
+        .map(|device| device.unwrap())
+        .filter(|(_, info)| info.max_output_channels > 0)
+        .map(|(device, _)| device)
+        .collect();
+    let error = DeviceSelector::output(i32::MAX)
+        .rule(Criteria::any())
+        .select::<f32>(&pa)
+        .unwrap_err();
+    let rejected: Vec<pa::DeviceIndex> = error
+        .rejections
+        .iter()
+        .map(|rejection| match rejection.reason {
+            RejectionReason::TooFewChannels { device, .. } => device,
+            ref reason => panic!("unexpected rejection: {}", reason),
+        })
+        .collect();
+    assert_eq!(rejected, output_devices);
+}
+
+#[test]
+fn no_rules_selects_nothing() {
+    let pa = pa::PortAudio::new().unwrap();
+    let error = DeviceSelector::output(1).select::<f32>(&pa).unwrap_err();
+    assert!(error.rejections.is_empty());
+}